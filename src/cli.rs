@@ -1,16 +1,36 @@
 use std::env;
+use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use color_eyre::eyre;
 use const_format::concatcp;
 
 use near_account_id::AccountId;
+use near_jsonrpc_client::header::{HeaderName, HeaderValue};
 
+mod account;
+mod add;
+mod batch;
+mod broadcast;
 mod call;
+mod create;
+mod delete;
+mod deploy;
 mod dissect;
+mod keygen;
+mod keys;
+mod keystore;
+mod send;
+mod state;
+mod status;
 
+use super::config::{self, Config};
+use super::credentials;
 use super::macros::error;
+use super::utils;
 
+pub use batch::BatchAction;
+pub use broadcast::BroadcastMode;
 pub use call::CallAction;
 
 pub const EXAMPLES_HEADER: &str = "\n
@@ -22,31 +42,421 @@ pub const EXAMPLES_HEADER: &str = "\n
     "\x1b[1;4mHint:\x1b[0m\n  \
     \x1b[1mnearx\x1b[0m call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
-    on \x1b[3mCONTRACT\x1b[0m \
-    [as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    on \x1b[3mCONTRACT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]\n  \
+    \x1b[1mnearx\x1b[0m send \
+    \x1b[3mAMOUNT\x1b[0m to \x1b[3mRECEIVER\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m deploy \
+    \x1b[3mWASM\x1b[0m [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m batch \
+    \x1b[3mACTIONS\x1b[0m [on \x1b[3mRECEIVER\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m broadcast \
+    \x1b[3mSIGNED_TX\x1b[0m [async] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m status \
+    \x1b[3mTX_HASH\x1b[0m from \x1b[3mSENDER\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m account \
+    \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]\n  \
+    \x1b[1mnearx\x1b[0m keys \
+    \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]\n  \
+    \x1b[1mnearx\x1b[0m key \
+    \x1b[3mPUBLIC_KEY\x1b[0m of \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]\n  \
+    \x1b[1mnearx\x1b[0m state \
+    \x1b[3mCONTRACT\x1b[0m [prefix \x1b[3mPREFIX\x1b[0m] [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]\n  \
+    \x1b[1mnearx\x1b[0m create account \
+    \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m delete account \
+    \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] beneficiary \x1b[3mBENEFICIARY\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m add key \
+    \x1b[3mPUBLIC_KEY\x1b[0m [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m delete key \
+    \x1b[3mPUBLIC_KEY\x1b[0m from \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m keygen \
+    [\x1b[3mKEY_TYPE\x1b[0m] [for \x1b[3mACCOUNT\x1b[0m] [save [\x1b[3mPATH\x1b[0m]]\n  \
+    \x1b[1mnearx\x1b[0m keystore \
+    import \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [on \x1b[3mNETWORK\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m keystore \
+    list [on \x1b[3mNETWORK\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m keystore \
+    export \x1b[3mACCOUNT\x1b[0m [on \x1b[3mNETWORK\x1b[0m]\n  \
+    \x1b[1mnearx\x1b[0m keystore \
+    remove \x1b[3mACCOUNT\x1b[0m [on \x1b[3mNETWORK\x1b[0m]",
     EXAMPLES_HEADER,
     " (\x1b[1mcall\x1b[0m)",
     call::EXAMPLES,
     EXAMPLES_HEADER,
+    " (\x1b[1msend\x1b[0m)",
+    send::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mdeploy\x1b[0m)",
+    deploy::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mbatch\x1b[0m)",
+    batch::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mbroadcast\x1b[0m)",
+    broadcast::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mstatus\x1b[0m)",
+    status::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1maccount\x1b[0m)",
+    account::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mkeys\x1b[0m)",
+    keys::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mstate\x1b[0m)",
+    state::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mcreate account\x1b[0m)",
+    create::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mdelete\x1b[0m)",
+    delete::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1madd key\x1b[0m)",
+    add::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mkeygen\x1b[0m)",
+    keygen::EXAMPLES,
+    EXAMPLES_HEADER,
+    " (\x1b[1mkeystore\x1b[0m)",
+    keystore::EXAMPLES,
+    EXAMPLES_HEADER,
     " (\x1b[1mdissect\x1b[0m)",
     dissect::EXAMPLES
 ))]
+struct RawCli {
+    #[clap(subcommand)]
+    command: RawCommand,
+
+    /// Render the receipt tree of executed transactions
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "tree"
+    )]
+    receipts: Option<ReceiptsFormat>,
+
+    /// Retry transient RPC failures this many times [default: 3]
+    #[clap(long, global = true, value_name = "N")]
+    retries: Option<u32>,
+
+    /// Send a `Name: value` header with every RPC request, can be repeated
+    #[clap(
+        long = "rpc-header",
+        global = true,
+        value_name = "HEADER",
+        value_parser = AuthHeader::parse
+    )]
+    rpc_headers: Vec<AuthHeader>,
+
+    /// Fall back to another RPC when the first one fails, can be repeated
+    #[clap(long = "fallback-rpc", global = true, value_name = "RPC_URL")]
+    fallback_rpc_urls: Vec<String>,
+
+    /// How long to wait for broadcast transactions
+    #[clap(long, global = true, value_enum, value_name = "STAGE", default_value_t)]
+    wait: WaitUntil,
+
+    /// Give up on RPC requests after this many seconds, polling the status of transactions
+    /// instead
+    #[clap(long, global = true, value_name = "SECONDS")]
+    timeout: Option<u64>,
+}
+
+#[derive(Debug, Subcommand)]
 enum RawCommand {
     Call(call::CallCommand),
+    Send(send::SendCommand),
+    Deploy(deploy::DeployCommand),
+    Batch(batch::BatchCommand),
+    Broadcast(broadcast::BroadcastCommand),
+    Status(status::StatusCommand),
+    Account(account::AccountCommand),
+    Keys(keys::KeysCommand),
+    Key(keys::KeyCommand),
+    State(state::StateCommand),
+    /// Creates accounts
+    #[clap(subcommand)]
+    Create(create::CreateCommand),
+    /// Deletes accounts and access keys
+    #[clap(subcommand)]
+    Delete(delete::DeleteCommand),
+    /// Adds access keys
+    #[clap(subcommand)]
+    Add(add::AddCommand),
+    Keygen(keygen::KeygenCommand),
+    /// Manages the encrypted keystore
+    #[clap(subcommand)]
+    Keystore(keystore::KeystoreCommand),
     Dissect(dissect::DissectCommand),
 }
 
 #[derive(Debug)]
 pub enum Command {
     Call(CallCommand),
+    Send(SendCommand),
+    Deploy(DeployCommand),
+    Batch(BatchCommand),
+    Broadcast(BroadcastCommand),
+    Status(StatusCommand),
+    Account(AccountCommand),
+    Keys(KeysCommand),
+    Key(KeyCommand),
+    State(StateCommand),
+    CreateAccount(CreateAccountCommand),
+    DeleteAccount(DeleteAccountCommand),
+    AddKey(AddKeyCommand),
+    DeleteKey(DeleteKeyCommand),
+    Keygen(KeygenCommand),
+    KeystoreImport(KeystoreImportCommand),
+    KeystoreList(KeystoreListCommand),
+    KeystoreExport(KeystoreExportCommand),
+    KeystoreRemove(KeystoreRemoveCommand),
     Dissect(DissectCommand),
 }
 
+/// How to render the receipt tree of an executed transaction.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ReceiptsFormat {
+    /// Draw the tree on stderr
+    Tree,
+    /// Print the tree as JSON on stdout
+    Json,
+}
+
+/// How long to wait for a broadcast transaction.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum WaitUntil {
+    /// Print the hash as soon as the RPC accepts the transaction
+    None,
+    /// Wait until the transaction is included in a block
+    Included,
+    /// Wait until the transaction and all of its receipts are executed
+    #[default]
+    Executed,
+    /// Wait until the blocks the transaction and its receipts were executed in are final
+    Final,
+}
+
+/// Options that apply to every command.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub receipts: Option<ReceiptsFormat>,
+    pub wait: WaitUntil,
+    /// Set by commands that can print their outcome as a single JSON document.
+    pub json: bool,
+    /// The explorer of the selected network, to link transactions to.
+    pub explorer_url: Option<String>,
+}
+
+/// The RPC to connect to.
+#[derive(Debug)]
+pub struct Rpc {
+    pub url: String,
+    /// Tried in turn when the RPC fails.
+    pub fallback_urls: Vec<String>,
+    /// Sent with every request, including the API key.
+    pub headers: Vec<AuthHeader>,
+    /// How many times to retry transient failures.
+    pub retries: u32,
+    /// How long to wait for a response before giving up on a request.
+    pub timeout: Option<std::time::Duration>,
+    pub explorer_url: Option<String>,
+}
+
+/// A header to authenticate RPC requests with.
+#[derive(Debug, Clone)]
+pub struct AuthHeader {
+    pub name: HeaderName,
+    pub value: HeaderValue,
+}
+
+impl AuthHeader {
+    /// An API key, sent as `x-api-key` whatever it contains.
+    pub fn api_key(api_key: &str) -> Result<Self, eyre::Error> {
+        Self::new(near_jsonrpc_client::auth::ApiKey::HEADER_NAME, api_key)
+    }
+
+    /// Parses a `Name: value` header.
+    pub fn parse(s: &str) -> Result<Self, eyre::Error> {
+        let Some((name, value)) = s.split_once(':') else {
+            eyre::bail!("invalid header `{}`, expected `Name: value`", s);
+        };
+
+        Self::new(name.trim(), value.trim())
+    }
+
+    fn new(name: &str, value: &str) -> Result<Self, eyre::Error> {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| eyre::eyre!("invalid header name `{}`", name))?;
+
+        let mut value = HeaderValue::from_str(value)
+            .map_err(|_| eyre::eyre!("invalid value for the `{}` header", name))?;
+        value.set_sensitive(true);
+
+        Ok(Self { name, value })
+    }
+}
+
+/// The gas attached to function calls when neither the command nor the network specifies it.
+const DEFAULT_GAS: near_primitives::types::Gas = utils::TGAS * 300;
+
+/// How many times to retry transient RPC failures when neither `--retries` nor the network
+/// specifies it.
+const DEFAULT_RETRIES: u32 = 3;
+
 impl Command {
-    pub fn parse() -> Result<Option<Self>, eyre::Error> {
-        match RawCommand::parse() {
-            RawCommand::Call(call) => Ok(CallCommand::parse(call)?.map(Command::Call)),
+    pub fn parse() -> Result<Option<(Self, Options)>, eyre::Error> {
+        let RawCli {
+            command,
+            receipts,
+            retries,
+            rpc_headers,
+            fallback_rpc_urls,
+            wait,
+            timeout,
+        } = RawCli::parse();
+
+        if receipts.is_some() && matches!(wait, WaitUntil::None | WaitUntil::Included) {
+            eyre::bail!(
+                "`--receipts` needs the transaction to be executed, so it can't be used with `--wait {}`",
+                wait.to_possible_value()
+                    .expect("no variant is skipped")
+                    .get_name()
+            );
+        }
+
+        // commands that don't connect to a network shouldn't fail over a broken config file
+        let config = match command {
+            RawCommand::Keygen(_) | RawCommand::Keystore(_) | RawCommand::Dissect(_) => {
+                Config::default()
+            }
+            _ => Config::load()?,
+        };
+
+        let Some(mut command) = Self::from_raw(command, &config)? else {
+            return Ok(None);
+        };
+
+        let mut explorer_url = None;
+        if let Some(rpc) = command.rpc_mut() {
+            rpc.retries = retries.unwrap_or(rpc.retries);
+            for header in rpc_headers {
+                rpc.headers.retain(|existing| existing.name != header.name);
+                rpc.headers.push(header);
+            }
+            rpc.fallback_urls.splice(0..0, fallback_rpc_urls);
+            if let Some(timeout) = timeout {
+                rpc.timeout = Some(std::time::Duration::from_secs(timeout));
+            }
+            explorer_url = rpc.explorer_url.clone();
+        }
+
+        let options = Options {
+            receipts,
+            wait,
+            json: false,
+            explorer_url,
+        };
+
+        Ok(Some((command, options)))
+    }
+
+    fn rpc_mut(&mut self) -> Option<&mut Rpc> {
+        match self {
+            Command::Call(command) => Some(&mut command.rpc),
+            Command::Send(command) => Some(&mut command.rpc),
+            Command::Deploy(command) => Some(&mut command.rpc),
+            Command::Batch(command) => Some(&mut command.rpc),
+            Command::Broadcast(command) => Some(&mut command.rpc),
+            Command::Status(command) => Some(&mut command.rpc),
+            Command::Account(command) => Some(&mut command.rpc),
+            Command::Keys(command) => Some(&mut command.rpc),
+            Command::Key(command) => Some(&mut command.rpc),
+            Command::State(command) => Some(&mut command.rpc),
+            Command::CreateAccount(command) => Some(&mut command.rpc),
+            Command::DeleteAccount(command) => Some(&mut command.rpc),
+            Command::AddKey(command) => Some(&mut command.rpc),
+            Command::DeleteKey(command) => Some(&mut command.rpc),
+            Command::Keygen(_)
+            | Command::KeystoreImport(_)
+            | Command::KeystoreList(_)
+            | Command::KeystoreExport(_)
+            | Command::KeystoreRemove(_)
+            | Command::Dissect(_) => None,
+        }
+    }
+
+    fn from_raw(command: RawCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        match command {
+            RawCommand::Call(call) => Ok(CallCommand::parse(call, config)?.map(Command::Call)),
+            RawCommand::Send(send) => Ok(SendCommand::parse(send, config)?.map(Command::Send)),
+            RawCommand::Deploy(deploy) => {
+                Ok(DeployCommand::parse(deploy, config)?.map(Command::Deploy))
+            }
+            RawCommand::Batch(batch) => Ok(BatchCommand::parse(batch, config)?.map(Command::Batch)),
+            RawCommand::Broadcast(broadcast) => {
+                Ok(BroadcastCommand::parse(broadcast, config)?.map(Command::Broadcast))
+            }
+            RawCommand::Status(status) => {
+                Ok(StatusCommand::parse(status, config)?.map(Command::Status))
+            }
+            RawCommand::Account(account) => {
+                Ok(AccountCommand::parse(account, config)?.map(Command::Account))
+            }
+            RawCommand::Keys(keys) => Ok(KeysCommand::parse(keys, config)?.map(Command::Keys)),
+            RawCommand::Key(key) => Ok(KeyCommand::parse(key, config)?.map(Command::Key)),
+            RawCommand::State(state) => Ok(StateCommand::parse(state, config)?.map(Command::State)),
+            RawCommand::Create(create::CreateCommand::Account(create)) => {
+                Ok(CreateAccountCommand::parse(create, config)?.map(Command::CreateAccount))
+            }
+            RawCommand::Delete(delete::DeleteCommand::Account(delete)) => {
+                Ok(DeleteAccountCommand::parse(delete, config)?.map(Command::DeleteAccount))
+            }
+            RawCommand::Add(add::AddCommand::Key(add)) => {
+                Ok(AddKeyCommand::parse(add, config)?.map(Command::AddKey))
+            }
+            RawCommand::Delete(delete::DeleteCommand::Key(delete)) => {
+                Ok(DeleteKeyCommand::parse(delete, config)?.map(Command::DeleteKey))
+            }
+            RawCommand::Keygen(keygen) => Ok(Some(Command::Keygen(KeygenCommand::parse(keygen)))),
+            RawCommand::Keystore(keystore::KeystoreCommand::Import(import)) => {
+                Ok(KeystoreImportCommand::parse(import).map(Command::KeystoreImport))
+            }
+            RawCommand::Keystore(keystore::KeystoreCommand::List(list)) => Ok(Some(
+                Command::KeystoreList(KeystoreListCommand::parse(list)),
+            )),
+            RawCommand::Keystore(keystore::KeystoreCommand::Export(export)) => {
+                Ok(KeystoreExportCommand::parse(export).map(Command::KeystoreExport))
+            }
+            RawCommand::Keystore(keystore::KeystoreCommand::Remove(remove)) => {
+                Ok(KeystoreRemoveCommand::parse(remove).map(Command::KeystoreRemove))
+            }
             RawCommand::Dissect(call) => Ok(Some(Command::Dissect(DissectCommand::parse(call)?))),
         }
     }
@@ -58,12 +468,14 @@ pub struct CallCommand {
     pub args: serde_json::Value,
     pub contract: AccountId,
     pub account: Option<call::AccountForTx>,
-    pub rpc_url: String,
-    pub rpc_api_key: Option<near_jsonrpc_client::auth::ApiKey>,
+    /// The block to call the contract at, if it's not a transaction.
+    pub block: near_primitives::types::BlockReference,
+    pub rpc: Rpc,
+    pub json: bool,
 }
 
 impl CallCommand {
-    fn parse(call: call::CallCommand) -> Result<Option<Self>, eyre::Error> {
+    fn parse(call: call::CallCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
         let mut command = call::ConsumableCommand::default();
 
         call.apply(&mut command);
@@ -76,43 +488,728 @@ impl CallCommand {
             .contract_id
             .expect("contract should've been set by now");
 
-        let account = command.signer_id.map(|signer_id| call::AccountForTx {
-            id: signer_id,
-            secret_key: command
-                .secret_key
-                .expect("secret key should've been set by now"),
-            deposit: command.deposit,
-            gas: command.gas,
-            action: command.action,
-        });
+        let network = network(command.rpc_url, config)?;
 
-        let rpc_url =
-            if let Some(rpc_url) = command.rpc_url.or_else(|| env::var("NEAR_RPC_URL").ok()) {
-                rpc_url
-            } else {
-                error!(
-                    "missing RPC URL, please specify `\x1b[1mthrough \x1b[3m<RPC_URL>\x1b[0m` \
-            or set `\x1b[1;3mNEAR_RPC_URL\x1b[0m` environment variable"
-                );
-                return Ok(None);
-            };
-
-        let mut rpc_api_key = command.rpc_api_key;
-        if let Ok(api_key) = env::var("NEAR_RPC_API_KEY") {
-            rpc_api_key = Some(near_jsonrpc_client::auth::ApiKey::new(api_key)?);
-        }
+        let Some(rpc) = tx_rpc(&network, command.rpc_api_key, &command.action)? else {
+            return Ok(None);
+        };
+
+        let account = match command.signer_id {
+            Some(signer_id) => {
+                let Some(secret_key) =
+                    secret_key(command.secret_key, &signer_id, &network, &rpc.url)?
+                else {
+                    return Ok(None);
+                };
+
+                Some(call::AccountForTx {
+                    id: signer_id,
+                    secret_key,
+                    deposit: command.deposit,
+                    gas: command.gas.or(network.gas).unwrap_or(DEFAULT_GAS),
+                    action: command.action,
+                })
+            }
+            None => None,
+        };
 
         Ok(Some(CallCommand {
             method,
             args,
             contract,
             account,
-            rpc_url,
-            rpc_api_key,
+            block: command.block,
+            rpc,
+            json: command.json,
         }))
     }
 }
 
+#[derive(Debug)]
+pub struct SendCommand {
+    pub amount: near_primitives::types::Balance,
+    pub receiver: AccountId,
+    pub signer_id: AccountId,
+    pub secret_key: near_crypto::SecretKey,
+    pub action: CallAction,
+    pub rpc: Rpc,
+}
+
+impl SendCommand {
+    fn parse(send: send::SendCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        let mut command = send::ConsumableCommand::default();
+
+        send.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = tx_rpc(&network, command.rpc_api_key, &command.action)? else {
+            return Ok(None);
+        };
+
+        let Some(signer_id) = signer_id(command.signer_id, &network) else {
+            return Ok(None);
+        };
+
+        let Some(secret_key) = secret_key(command.secret_key, &signer_id, &network, &rpc.url)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(SendCommand {
+            amount: command.amount.expect("amount should've been set by now"),
+            receiver: command
+                .receiver_id
+                .expect("receiver should've been set by now"),
+            signer_id,
+            secret_key,
+            action: command.action,
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct DeployCommand {
+    /// `None` reads the contract code from stdin.
+    pub wasm: Option<PathBuf>,
+    pub signer_id: AccountId,
+    pub secret_key: near_crypto::SecretKey,
+    pub init: Option<InitCall>,
+    pub action: CallAction,
+    pub rpc: Rpc,
+}
+
+#[derive(Debug)]
+pub struct InitCall {
+    pub method: String,
+    pub args: serde_json::Value,
+    pub gas: near_primitives::types::Gas,
+    pub deposit: near_primitives::types::Balance,
+}
+
+impl DeployCommand {
+    fn parse(deploy: deploy::DeployCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        let mut command = deploy::ConsumableCommand::default();
+
+        deploy.apply(&mut command);
+
+        let wasm = command.wasm.expect("wasm should've been set by now");
+        let wasm = (wasm.as_os_str() != "-").then_some(wasm);
+
+        let network = network(command.rpc_url, config)?;
+
+        let init = command.init_method.map(|method| InitCall {
+            method,
+            args: command.init_args,
+            gas: command.init_gas.or(network.gas).unwrap_or(DEFAULT_GAS),
+            deposit: command.init_deposit,
+        });
+
+        let Some(rpc) = tx_rpc(&network, command.rpc_api_key, &command.action)? else {
+            return Ok(None);
+        };
+
+        let Some(signer_id) = signer_id(command.signer_id, &network) else {
+            return Ok(None);
+        };
+
+        let Some(secret_key) = secret_key(command.secret_key, &signer_id, &network, &rpc.url)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(DeployCommand {
+            wasm,
+            signer_id,
+            secret_key,
+            init,
+            action: command.action,
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct BatchCommand {
+    /// `None` reads the actions from stdin.
+    pub actions: Option<Vec<BatchAction>>,
+    /// `None` sends the actions to the signer.
+    pub receiver: Option<AccountId>,
+    pub signer_id: AccountId,
+    pub secret_key: near_crypto::SecretKey,
+    /// The gas of calls that don't specify it, if the network has a default.
+    pub gas: Option<near_primitives::types::Gas>,
+    pub action: CallAction,
+    pub rpc: Rpc,
+}
+
+impl BatchCommand {
+    fn parse(batch: batch::BatchCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        let mut command = batch::ConsumableCommand::default();
+
+        batch.apply(&mut command);
+
+        let actions = match command
+            .actions
+            .expect("actions should've been set by now")
+            .as_str()
+        {
+            "-" => None,
+            actions => Some(BatchAction::parse_list(actions)?),
+        };
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = tx_rpc(&network, command.rpc_api_key, &command.action)? else {
+            return Ok(None);
+        };
+
+        let Some(signer_id) = signer_id(command.signer_id, &network) else {
+            return Ok(None);
+        };
+
+        let Some(secret_key) = secret_key(command.secret_key, &signer_id, &network, &rpc.url)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(BatchCommand {
+            actions,
+            receiver: command.receiver_id,
+            signer_id,
+            secret_key,
+            gas: network.gas,
+            action: command.action,
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct BroadcastCommand {
+    /// `None` reads the signed transaction from stdin.
+    pub signed_tx: Option<dissect::SignedTransaction>,
+    pub mode: BroadcastMode,
+    pub rpc: Rpc,
+}
+
+impl BroadcastCommand {
+    fn parse(
+        broadcast: broadcast::BroadcastCommand,
+        config: &Config,
+    ) -> Result<Option<Self>, eyre::Error> {
+        let mut command = broadcast::ConsumableCommand::default();
+
+        broadcast.apply(&mut command);
+
+        let signed_tx = match command
+            .signed_tx
+            .expect("signed transaction should've been set by now")
+            .as_str()
+        {
+            "-" => None,
+            signed_tx => Some(
+                dissect::signed_tx_from_str(signed_tx)
+                    .map_err(|err| eyre::eyre!("failed to parse signed transaction: {}", err))?,
+            ),
+        };
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = rpc(&network, command.rpc_api_key)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(BroadcastCommand {
+            signed_tx,
+            mode: command.mode,
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct StatusCommand {
+    pub tx_hash: near_primitives::hash::CryptoHash,
+    pub sender_id: AccountId,
+    pub rpc: Rpc,
+}
+
+impl StatusCommand {
+    fn parse(status: status::StatusCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        let mut command = status::ConsumableCommand::default();
+
+        status.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = rpc(&network, command.rpc_api_key)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(StatusCommand {
+            tx_hash: command
+                .tx_hash
+                .expect("transaction hash should've been set by now"),
+            sender_id: command.sender_id.expect("sender should've been set by now"),
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct AccountCommand {
+    pub account_id: AccountId,
+    pub block: near_primitives::types::BlockReference,
+    pub rpc: Rpc,
+    pub json: bool,
+}
+
+impl AccountCommand {
+    fn parse(
+        account: account::AccountCommand,
+        config: &Config,
+    ) -> Result<Option<Self>, eyre::Error> {
+        let mut command = account::ConsumableCommand::default();
+
+        account.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = rpc(&network, command.rpc_api_key)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(AccountCommand {
+            account_id: command
+                .account_id
+                .expect("account should've been set by now"),
+            block: command
+                .block
+                .unwrap_or_else(near_primitives::types::BlockReference::latest),
+            rpc,
+            json: command.json,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct KeysCommand {
+    pub account_id: AccountId,
+    pub block: near_primitives::types::BlockReference,
+    pub rpc: Rpc,
+    pub json: bool,
+}
+
+impl KeysCommand {
+    fn parse(keys: keys::KeysCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        let mut command = keys::ConsumableCommand::default();
+
+        keys.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = rpc(&network, command.rpc_api_key)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(KeysCommand {
+            account_id: command
+                .account_id
+                .expect("account should've been set by now"),
+            block: command
+                .block
+                .unwrap_or_else(near_primitives::types::BlockReference::latest),
+            rpc,
+            json: command.json,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct KeyCommand {
+    pub account_id: AccountId,
+    pub public_key: near_crypto::PublicKey,
+    pub block: near_primitives::types::BlockReference,
+    pub rpc: Rpc,
+    pub json: bool,
+}
+
+impl KeyCommand {
+    fn parse(key: keys::KeyCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        let mut command = keys::ConsumableCommand::default();
+
+        key.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = rpc(&network, command.rpc_api_key)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(KeyCommand {
+            account_id: command
+                .account_id
+                .expect("account should've been set by now"),
+            public_key: command
+                .public_key
+                .expect("public key should've been set by now"),
+            block: command
+                .block
+                .unwrap_or_else(near_primitives::types::BlockReference::latest),
+            rpc,
+            json: command.json,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct StateCommand {
+    pub contract_id: AccountId,
+    pub prefix: near_primitives::types::StoreKey,
+    pub block: near_primitives::types::BlockReference,
+    pub rpc: Rpc,
+    pub json: bool,
+}
+
+impl StateCommand {
+    fn parse(state: state::StateCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        let mut command = state::ConsumableCommand::default();
+
+        state.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = rpc(&network, command.rpc_api_key)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(StateCommand {
+            contract_id: command
+                .contract_id
+                .expect("contract should've been set by now"),
+            prefix: command.prefix.unwrap_or_else(|| vec![].into()),
+            block: command
+                .block
+                .unwrap_or_else(near_primitives::types::BlockReference::latest),
+            rpc,
+            json: command.json,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateAccountCommand {
+    pub new_account_id: AccountId,
+    /// `None` reuses the signer's public key.
+    pub public_key: Option<near_crypto::PublicKey>,
+    pub deposit: near_primitives::types::Balance,
+    pub signer_id: AccountId,
+    pub secret_key: near_crypto::SecretKey,
+    pub action: CallAction,
+    pub rpc: Rpc,
+}
+
+impl CreateAccountCommand {
+    fn parse(
+        create: create::CreateAccountCommand,
+        config: &Config,
+    ) -> Result<Option<Self>, eyre::Error> {
+        let mut command = create::ConsumableCommand::default();
+
+        create.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = tx_rpc(&network, command.rpc_api_key, &command.action)? else {
+            return Ok(None);
+        };
+
+        let Some(signer_id) = signer_id(command.signer_id, &network) else {
+            return Ok(None);
+        };
+
+        let Some(secret_key) = secret_key(command.secret_key, &signer_id, &network, &rpc.url)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(CreateAccountCommand {
+            new_account_id: command
+                .new_account_id
+                .expect("new account should've been set by now"),
+            public_key: command.public_key,
+            deposit: command.deposit,
+            signer_id,
+            secret_key,
+            action: command.action,
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteAccountCommand {
+    pub account_id: AccountId,
+    pub secret_key: near_crypto::SecretKey,
+    pub beneficiary_id: AccountId,
+    pub action: CallAction,
+    pub rpc: Rpc,
+}
+
+impl DeleteAccountCommand {
+    fn parse(
+        delete: delete::DeleteAccountCommand,
+        config: &Config,
+    ) -> Result<Option<Self>, eyre::Error> {
+        let mut command = delete::ConsumableCommand::default();
+
+        delete.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = tx_rpc(&network, command.rpc_api_key, &command.action)? else {
+            return Ok(None);
+        };
+
+        let account_id = command
+            .account_id
+            .expect("account should've been set by now");
+
+        let Some(secret_key) = secret_key(command.secret_key, &account_id, &network, &rpc.url)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(DeleteAccountCommand {
+            account_id,
+            secret_key,
+            beneficiary_id: command
+                .beneficiary_id
+                .expect("beneficiary should've been set by now"),
+            action: command.action,
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct AddKeyCommand {
+    pub public_key: near_crypto::PublicKey,
+    pub permission: near_primitives::views::AccessKeyPermissionView,
+    pub account_id: AccountId,
+    pub secret_key: near_crypto::SecretKey,
+    pub action: CallAction,
+    pub rpc: Rpc,
+}
+
+impl AddKeyCommand {
+    fn parse(add: add::AddKeyCommand, config: &Config) -> Result<Option<Self>, eyre::Error> {
+        let mut command = add::ConsumableCommand::default();
+
+        add.apply(&mut command);
+
+        let permission = match command.receiver_id {
+            Some(receiver_id) => near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance: command.allowance,
+                receiver_id: receiver_id.into(),
+                method_names: command.method_names,
+            },
+            None => near_primitives::views::AccessKeyPermissionView::FullAccess,
+        };
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = tx_rpc(&network, command.rpc_api_key, &command.action)? else {
+            return Ok(None);
+        };
+
+        let account_id = command
+            .account_id
+            .expect("account should've been set by now");
+
+        let Some(secret_key) = secret_key(command.secret_key, &account_id, &network, &rpc.url)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(AddKeyCommand {
+            public_key: command
+                .public_key
+                .expect("public key should've been set by now"),
+            permission,
+            account_id,
+            secret_key,
+            action: command.action,
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteKeyCommand {
+    pub public_key: near_crypto::PublicKey,
+    pub account_id: AccountId,
+    pub secret_key: near_crypto::SecretKey,
+    pub action: CallAction,
+    pub rpc: Rpc,
+}
+
+impl DeleteKeyCommand {
+    fn parse(
+        delete: delete::DeleteKeyCommand,
+        config: &Config,
+    ) -> Result<Option<Self>, eyre::Error> {
+        let mut command = delete::ConsumableCommand::default();
+
+        delete.apply(&mut command);
+
+        let network = network(command.rpc_url, config)?;
+
+        let Some(rpc) = tx_rpc(&network, command.rpc_api_key, &command.action)? else {
+            return Ok(None);
+        };
+
+        let account_id = command
+            .account_id
+            .expect("account should've been set by now");
+
+        let Some(secret_key) = secret_key(command.secret_key, &account_id, &network, &rpc.url)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(DeleteKeyCommand {
+            public_key: command
+                .public_key
+                .expect("public key should've been set by now"),
+            account_id,
+            secret_key,
+            action: command.action,
+            rpc,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct KeygenCommand {
+    pub key_type: near_crypto::KeyType,
+    pub account_id: Option<AccountId>,
+    /// `Some(None)` saves to the near-cli credentials of the account.
+    pub save: Option<Option<PathBuf>>,
+}
+
+impl KeygenCommand {
+    fn parse(keygen: keygen::KeygenCommand) -> Self {
+        let mut command = keygen::ConsumableCommand::default();
+
+        keygen.apply(&mut command);
+
+        KeygenCommand {
+            key_type: command.key_type.into(),
+            account_id: command.account_id,
+            save: command.save,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct KeystoreImportCommand {
+    pub account_id: AccountId,
+    /// `None` prompts for the key.
+    pub secret_key: Option<near_crypto::SecretKey>,
+    pub network: String,
+}
+
+impl KeystoreImportCommand {
+    fn parse(import: keystore::KeystoreImportCommand) -> Option<Self> {
+        let mut command = keystore::ConsumableCommand::default();
+
+        import.apply(&mut command);
+
+        let account_id = command
+            .account_id
+            .expect("account should've been set by now");
+
+        let network = keystore_network(command.network, &account_id)?;
+
+        Some(KeystoreImportCommand {
+            account_id,
+            secret_key: command.secret_key,
+            network,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct KeystoreListCommand {
+    /// `None` lists the keys of every network.
+    pub network: Option<String>,
+}
+
+impl KeystoreListCommand {
+    fn parse(list: keystore::KeystoreListCommand) -> Self {
+        let mut command = keystore::ConsumableCommand::default();
+
+        list.apply(&mut command);
+
+        KeystoreListCommand {
+            network: command.network.or_else(|| env::var("NEAR_ENV").ok()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct KeystoreExportCommand {
+    pub account_id: AccountId,
+    pub network: String,
+}
+
+impl KeystoreExportCommand {
+    fn parse(export: keystore::KeystoreExportCommand) -> Option<Self> {
+        let mut command = keystore::ConsumableCommand::default();
+
+        export.apply(&mut command);
+
+        let account_id = command
+            .account_id
+            .expect("account should've been set by now");
+
+        let network = keystore_network(command.network, &account_id)?;
+
+        Some(KeystoreExportCommand {
+            account_id,
+            network,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct KeystoreRemoveCommand {
+    pub account_id: AccountId,
+    pub network: String,
+}
+
+impl KeystoreRemoveCommand {
+    fn parse(remove: keystore::KeystoreRemoveCommand) -> Option<Self> {
+        let mut command = keystore::ConsumableCommand::default();
+
+        remove.apply(&mut command);
+
+        let account_id = command
+            .account_id
+            .expect("account should've been set by now");
+
+        let network = keystore_network(command.network, &account_id)?;
+
+        Some(KeystoreRemoveCommand {
+            account_id,
+            network,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct DissectCommand {
     pub signed_tx: Option<dissect::SignedTransaction>,
@@ -127,3 +1224,239 @@ impl DissectCommand {
         })
     }
 }
+
+/// Picks the network from `through`, `NEARX_NETWORK`, `NEAR_RPC_URL` or the config file,
+/// in that order.
+fn network(through: Option<String>, config: &Config) -> Result<config::Network, eyre::Error> {
+    let lookup = |name: &str| {
+        config.network(name).ok_or_else(|| {
+            eyre::eyre!(
+                "unknown network `{}`, expected an RPC URL or a network defined in `{}`",
+                name,
+                config::path().map_or_else(
+                    || "~/.config/nearx/config.toml".to_owned(),
+                    |path| path.display().to_string()
+                )
+            )
+        })
+    };
+
+    let url = |rpc_url| config::Network {
+        rpc_url: Some(rpc_url),
+        ..config::Network::default()
+    };
+
+    if let Some(through) = through {
+        if through.contains("://") {
+            return Ok(url(through));
+        }
+        return lookup(&through);
+    }
+
+    if let Ok(name) = env::var("NEARX_NETWORK") {
+        return lookup(&name);
+    }
+
+    if let Ok(rpc_url) = env::var("NEAR_RPC_URL") {
+        return Ok(url(rpc_url));
+    }
+
+    match &config.network {
+        Some(name) => lookup(name),
+        None => Ok(config::Network::default()),
+    }
+}
+
+fn rpc(
+    network: &config::Network,
+    rpc_api_key: Option<AuthHeader>,
+) -> Result<Option<Rpc>, eyre::Error> {
+    let Some(url) = network.rpc_url.clone() else {
+        error!(
+            "missing RPC URL, please specify `\x1b[1mthrough \x1b[3m<RPC_URL>\x1b[0m`, \
+            set `\x1b[1;3mNEAR_RPC_URL\x1b[0m` or `\x1b[1;3mNEARX_NETWORK\x1b[0m` environment variable \
+            or a default network in the config file"
+        );
+        return Ok(None);
+    };
+
+    Ok(Some(Rpc {
+        url,
+        fallback_urls: network.fallback_rpc_urls.clone(),
+        headers: rpc_headers(rpc_api_key, network)?,
+        retries: network.retries.unwrap_or(DEFAULT_RETRIES),
+        timeout: network.timeout.map(std::time::Duration::from_secs),
+        explorer_url: network.explorer_url.clone(),
+    }))
+}
+
+/// Offline signing never connects to the RPC, so it doesn't require one.
+fn tx_rpc(
+    network: &config::Network,
+    rpc_api_key: Option<AuthHeader>,
+    action: &CallAction,
+) -> Result<Option<Rpc>, eyre::Error> {
+    if let CallAction::Offline { .. } = action {
+        return Ok(Some(Rpc {
+            url: network.rpc_url.clone().unwrap_or_default(),
+            fallback_urls: vec![],
+            headers: vec![],
+            retries: 0,
+            timeout: None,
+            explorer_url: network.explorer_url.clone(),
+        }));
+    }
+
+    rpc(network, rpc_api_key)
+}
+
+/// Falls back to the default signer of the network.
+fn signer_id(signer_id: Option<AccountId>, network: &config::Network) -> Option<AccountId> {
+    let signer_id = signer_id.or_else(|| network.signer.clone());
+
+    if signer_id.is_none() {
+        error!(
+            "missing signer, please specify `\x1b[1mas \x1b[3m<ACCOUNT>\x1b[0m` \
+            or a default signer for the network in the config file"
+        );
+    }
+
+    signer_id
+}
+
+fn keystore_network(network: Option<String>, account_id: &AccountId) -> Option<String> {
+    let network = network.or_else(|| credentials::network(account_id, ""));
+
+    if network.is_none() {
+        error!(
+            "couldn't tell which network `\x1b[1m{}\x1b[0m` is on, please specify \
+            `\x1b[1mon \x1b[3m<NETWORK>\x1b[0m` or set `\x1b[1;3mNEAR_ENV\x1b[0m` environment variable",
+            account_id
+        );
+    }
+
+    network
+}
+
+/// Falls back to `NEAR_SECRET_KEY`, then to the keystore, then to the near-cli credentials
+/// of the account, on the selected network unless it has to be guessed.
+fn secret_key(
+    secret_key: Option<near_crypto::SecretKey>,
+    account_id: &AccountId,
+    network: &config::Network,
+    rpc_url: &str,
+) -> Result<Option<near_crypto::SecretKey>, eyre::Error> {
+    if let Some(secret_key) = secret_key {
+        return Ok(Some(secret_key));
+    }
+
+    if let Ok(secret_key) = env::var("NEAR_SECRET_KEY") {
+        return credentials::secret_key_from_str(&secret_key).map(Some);
+    }
+
+    let network = network
+        .network_id
+        .clone()
+        .or_else(|| credentials::network(account_id, rpc_url));
+
+    if let Some(network) = &network {
+        if let Some(key) = crate::keystore::read(network, account_id)? {
+            let passphrase =
+                crate::keystore::passphrase(&format!("passphrase for {}: ", account_id))?;
+            return key.decrypt(&passphrase).map(Some);
+        }
+
+        if let Some(secret_key) = credentials::lookup(account_id, network)? {
+            return Ok(Some(secret_key));
+        }
+    }
+
+    let path = network
+        .and_then(|network| credentials::credentials_path(account_id, &network))
+        .map_or_else(
+            || format!("~/.near-credentials/<NETWORK>/{}.json", account_id),
+            |path| path.display().to_string(),
+        );
+
+    error!(
+        "missing secret key for `\x1b[1m{}\x1b[0m`, please specify `\x1b[1mwith \x1b[3m<SECRET>\x1b[0m`, \
+        set `\x1b[1;3mNEAR_SECRET_KEY\x1b[0m` environment variable, \
+        add it with `\x1b[1mnearx keystore import\x1b[0m` or save it to `\x1b[1m{}\x1b[0m`",
+        account_id,
+        path
+    );
+
+    Ok(None)
+}
+
+/// Prefers `with TOKEN` over `NEAR_RPC_API_KEY`, over the API key of the network.
+fn rpc_headers(
+    rpc_api_key: Option<AuthHeader>,
+    network: &config::Network,
+) -> Result<Vec<AuthHeader>, eyre::Error> {
+    let mut headers = network
+        .headers
+        .iter()
+        .map(|(name, value)| AuthHeader::new(name, value))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let api_key = match rpc_api_key {
+        Some(api_key) => Some(api_key),
+        None => match env::var("NEAR_RPC_API_KEY") {
+            Ok(api_key) => Some(AuthHeader::api_key(&api_key)?),
+            Err(_) => network
+                .api_key
+                .as_deref()
+                .map(AuthHeader::api_key)
+                .transpose()?,
+        },
+    };
+
+    if let Some(api_key) = api_key {
+        headers.retain(|header| header.name != api_key.name);
+        headers.push(api_key);
+    }
+
+    Ok(headers)
+}
+
+/// Parses a block height, a block hash or a finality.
+fn block_reference_from_str(
+    s: &str,
+) -> Result<near_primitives::types::BlockReference, eyre::Error> {
+    use near_primitives::types::{BlockId, BlockReference, Finality};
+
+    match s {
+        "final" => return Ok(BlockReference::Finality(Finality::Final)),
+        "near-final" | "doomslug" => return Ok(BlockReference::Finality(Finality::DoomSlug)),
+        "optimistic" | "latest" => return Ok(BlockReference::Finality(Finality::None)),
+        _ => {}
+    }
+
+    if let Ok(height) = s.parse() {
+        return Ok(BlockReference::BlockId(BlockId::Height(height)));
+    }
+
+    s.parse()
+        .map(|hash| BlockReference::BlockId(BlockId::Hash(hash)))
+        .map_err(|_| {
+            eyre::eyre!(
+                "`{}` is neither a block height, a block hash, `final`, `near-final` nor `optimistic`",
+                s
+            )
+        })
+}
+
+fn block_hash_from_str(s: &str) -> Result<near_primitives::hash::CryptoHash, eyre::Error> {
+    if let Ok(block_hash) = s.parse() {
+        return Ok(block_hash);
+    }
+
+    let contents = std::fs::read_to_string(s)
+        .map_err(|_| eyre::eyre!("`{}` is neither a block hash nor a readable file", s))?;
+
+    contents
+        .trim()
+        .parse()
+        .map_err(|err| eyre::eyre!("invalid block hash in `{}`: {}", s, err))
+}
@@ -0,0 +1,152 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+pub const EXAMPLES: &str = "
+  # Look up the balance, storage and code of `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx account \x1b[1;3m\"bob.testnet\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Look up `\x1b[1mbob.testnet\x1b[0m` as of block `\x1b[1m112233\x1b[0m`, as JSON
+  $ nearx account \x1b[1;3m\"bob.testnet\"\x1b[0m at \x1b[1;3m112233\x1b[0m through \x1b[1;3m\"https://archival-rpc.testnet.near.org\"\x1b[0m --json";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1maccount \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Looks up the balance, storage and code of an account
+pub struct AccountCommand {
+    /// The account to look up.
+    account: AccountId,
+
+    /// Print the account as a single JSON document
+    #[clap(long, global = true)]
+    json: bool,
+
+    #[clap(subcommand)]
+    rest: Option<AccountCommandRest>,
+}
+
+impl AccountCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        command.json = self.json;
+        match self.rest {
+            Some(AccountCommandRest::At(at_block)) => at_block.apply(command),
+            Some(AccountCommandRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AccountCommandRest {
+    At(AccountCommandRestAt),
+    Through(AccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx account \x1b[3mACCOUNT\x1b[0m \x1b[1mat \x1b[3mBLOCK\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the block to look the account up at.
+struct AccountCommandRestAt {
+    /// The block to look the account up at.
+    /// Format: `<height|hash|final|near-final|optimistic>`, e.g. `112233`, `final`
+    #[clap(
+        verbatim_doc_comment,
+        value_name = "BLOCK",
+        value_parser = super::call::block_reference_from_str
+    )]
+    block: near_primitives::types::BlockReference,
+
+    #[clap(subcommand)]
+    rest: Option<AccountCommandRestAtRest>,
+}
+
+impl AccountCommandRestAt {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.block = Some(self.block);
+        match self.rest {
+            Some(AccountCommandRestAtRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AccountCommandRestAtRest {
+    Through(AccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx account \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct AccountCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<AccountCommandThroughRest>,
+}
+
+impl AccountCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(AccountCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AccountCommandThroughRest {
+    With(AccountCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx account \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct AccountCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl AccountCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+#[derive(Default)]
+pub struct ConsumableCommand {
+    pub account_id: Option<AccountId>,
+    pub block: Option<near_primitives::types::BlockReference>,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+    pub json: bool,
+}
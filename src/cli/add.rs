@@ -0,0 +1,397 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+use crate::utils;
+
+use super::CallAction;
+
+pub const EXAMPLES: &str = "
+  # Add a full access key to `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx add key \x1b[1;3m\"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847\"\x1b[0m to \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Add a key to `\x1b[1mbob.testnet\x1b[0m` that can only call `\x1b[1maddGreeting\x1b[0m` on `\x1b[1mgreeter.testnet\x1b[0m`, with a `\x1b[1m0.25 Ⓝ\x1b[0m` allowance for fees
+  $ nearx add key \x1b[1;3m\"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847\"\x1b[0m for \x1b[1;3m\"greeter.testnet\"\x1b[0m methods \x1b[1;3m\"addGreeting\"\x1b[0m allowance \x1b[1;3m\"0.25N\"\x1b[0m to \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Display a transaction that adds a key to `\x1b[1mderek.testnet\x1b[0m` that can call any method on `\x1b[1mgreeter.testnet\x1b[0m`
+  $ nearx add key \x1b[1;3m\"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847\"\x1b[0m for \x1b[1;3m\"greeter.testnet\"\x1b[0m to \x1b[1;3m\"derek.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m display";
+
+#[derive(Debug, Subcommand)]
+pub enum AddCommand {
+    Key(AddKeyCommand),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add \x1b[1mkey \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Adds an access key to an account
+pub struct AddKeyCommand {
+    /// The public key to add.
+    /// Format: `<ed25519|secp256k1>:<bs58 public key>`
+    #[clap(verbatim_doc_comment)]
+    public_key: near_crypto::PublicKey,
+
+    #[clap(subcommand)]
+    rest: AddKeyCommandRest,
+}
+
+impl AddKeyCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.public_key = Some(self.public_key);
+        match self.rest {
+            AddKeyCommandRest::For(for_contract) => for_contract.apply(command),
+            AddKeyCommandRest::To(to_account) => to_account.apply(command),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AddKeyCommandRest {
+    For(AddKeyCommandRestFor),
+    To(AddKeyCommandTo),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    \x1b[1mfor \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Restricts the key to calling methods on a contract. (Makes this a function call key.)
+struct AddKeyCommandRestFor {
+    /// The contract the key is allowed to call.
+    contract: AccountId,
+
+    #[clap(subcommand)]
+    rest: AddKeyCommandRestForRest,
+}
+
+impl AddKeyCommandRestFor {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.receiver_id = Some(self.contract);
+        match self.rest {
+            AddKeyCommandRestForRest::Methods(methods) => methods.apply(command),
+            AddKeyCommandRestForRest::Allowance(allowance) => allowance.apply(command),
+            AddKeyCommandRestForRest::To(to_account) => to_account.apply(command),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AddKeyCommandRestForRest {
+    Methods(AddKeyCommandRestForRestMethods),
+    Allowance(AddKeyCommandAllowance),
+    To(AddKeyCommandTo),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m \x1b[1mmethods \x1b[3mMETHODS\x1b[0m [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Restricts the key to calling specific methods. (Defaults to any method.)
+struct AddKeyCommandRestForRestMethods {
+    /// Comma-separated list of methods the key is allowed to call.
+    #[clap(value_name = "METHODS")]
+    methods: String,
+
+    #[clap(subcommand)]
+    rest: AddKeyCommandRestForRestMethodsRest,
+}
+
+impl AddKeyCommandRestForRestMethods {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.method_names = self
+            .methods
+            .split(',')
+            .map(str::trim)
+            .filter(|method| !method.is_empty())
+            .map(String::from)
+            .collect();
+        match self.rest {
+            AddKeyCommandRestForRestMethodsRest::Allowance(allowance) => allowance.apply(command),
+            AddKeyCommandRestForRestMethodsRest::To(to_account) => to_account.apply(command),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AddKeyCommandRestForRestMethodsRest {
+    Allowance(AddKeyCommandAllowance),
+    To(AddKeyCommandTo),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] \x1b[1mallowance \x1b[3mALLOWANCE\x1b[0m] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines how much NEAR the key can spend on fees. (Defaults to unlimited.)
+struct AddKeyCommandAllowance {
+    /// The amount of NEAR the key can spend on fees.
+    /// Format: `<amount><NEAR|N|Ⓝ|milliNEAR|yoctoNEAR>`, e.g. `5N`, `0.25 NEAR`, `10yocto`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_balance)]
+    allowance: near_primitives::types::Balance,
+
+    #[clap(subcommand)]
+    rest: AddKeyCommandAllowanceRest,
+}
+
+impl AddKeyCommandAllowance {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.allowance = Some(self.allowance);
+        let AddKeyCommandAllowanceRest::To(to_account) = self.rest;
+        to_account.apply(command);
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AddKeyCommandAllowanceRest {
+    To(AddKeyCommandTo),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    \x1b[1mto \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to add the key to.
+struct AddKeyCommandTo {
+    /// The account to add the key to.
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<AddKeyCommandToRest>,
+}
+
+impl AddKeyCommandTo {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        match self.rest {
+            Some(AddKeyCommandToRest::With(with_secret_key)) => with_secret_key.apply(command),
+            Some(AddKeyCommandToRest::Display(display)) => display.apply(command),
+            Some(AddKeyCommandToRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AddKeyCommandToRest {
+    With(AddKeyCommandToRestWith),
+    Display(AddKeyCommandDisplay),
+    Through(AddKeyCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    to \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the secret key to use.
+struct AddKeyCommandToRestWith {
+    /// The private key or seed phrase to use, or a file containing the key. [env: NEAR_SECRET_KEY]
+    /// Format: `<ed25519|secp256k1>:<bs58 private key>`
+    #[clap(verbatim_doc_comment, value_parser = super::call::secret_key_from_str)]
+    private_key: near_crypto::SecretKey,
+
+    #[clap(subcommand)]
+    rest: Option<AddKeyCommandToRestWithRest>,
+}
+
+impl AddKeyCommandToRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.secret_key = Some(self.private_key);
+        match self.rest {
+            Some(AddKeyCommandToRestWithRest::Display(display)) => display.apply(command),
+            Some(AddKeyCommandToRestWithRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AddKeyCommandToRestWithRest {
+    Display(AddKeyCommandDisplay),
+    Through(AddKeyCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] \x1b[1mdisplay\x1b[0m [offline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Displays the transaction that would be sent.
+struct AddKeyCommandDisplay {
+    #[clap(subcommand)]
+    rest: Option<AddKeyCommandDisplayRest>,
+}
+
+impl AddKeyCommandDisplay {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Display;
+        match self.rest {
+            Some(AddKeyCommandDisplayRest::Offline(offline)) => offline.apply(command),
+            Some(AddKeyCommandDisplayRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AddKeyCommandDisplayRest {
+    Offline(AddKeyCommandDisplayOffline),
+    Through(AddKeyCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] display \x1b[1moffline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Signs the transaction without connecting to an RPC.
+struct AddKeyCommandDisplayOffline {
+    /// The nonce to sign the transaction with. (The access key's current nonce + 1.)
+    nonce: near_primitives::types::Nonce,
+
+    /// A recent block hash, or a file containing one.
+    #[clap(value_parser = super::block_hash_from_str)]
+    block_hash: near_primitives::hash::CryptoHash,
+}
+
+impl AddKeyCommandDisplayOffline {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Offline {
+            nonce: self.nonce,
+            block_hash: self.block_hash,
+        };
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct AddKeyCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<AddKeyCommandThroughRest>,
+}
+
+impl AddKeyCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(AddKeyCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AddKeyCommandThroughRest {
+    With(AddKeyCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx add key \x1b[3mPUBLIC_KEY\x1b[0m \
+    [for \x1b[3mCONTRACT\x1b[0m [methods \x1b[3mMETHODS\x1b[0m] [allowance \x1b[3mALLOWANCE\x1b[0m]] \
+    to \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct AddKeyCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl AddKeyCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+pub struct ConsumableCommand {
+    pub public_key: Option<near_crypto::PublicKey>,
+    pub receiver_id: Option<AccountId>,
+    pub method_names: Vec<String>,
+    pub allowance: Option<near_primitives::types::Balance>,
+    pub account_id: Option<AccountId>,
+    pub secret_key: Option<near_crypto::SecretKey>,
+    pub action: CallAction,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+}
+
+impl Default for ConsumableCommand {
+    fn default() -> Self {
+        Self {
+            public_key: None,
+            receiver_id: None,
+            method_names: vec![],
+            allowance: None,
+            account_id: None,
+            secret_key: None,
+            action: CallAction::Submit,
+            rpc_url: None,
+            rpc_api_key: None,
+        }
+    }
+}
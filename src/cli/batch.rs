@@ -0,0 +1,417 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+use serde::Deserialize;
+
+use near_account_id::AccountId;
+
+use crate::utils;
+
+use super::CallAction;
+
+pub const EXAMPLES: &str = "
+  # Deploy `\x1b[1mcontract.wasm\x1b[0m` to `\x1b[1mbob.testnet\x1b[0m` and call `\x1b[1mmigrate()\x1b[0m` in the same transaction
+  $ nearx batch \x1b[1;3m'[{\"action\":\"deploy\",\"wasm\":\"contract.wasm\"},{\"action\":\"call\",\"method\":\"migrate\"}]'\x1b[0m as \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Call two methods on `\x1b[1mgreeter.testnet\x1b[0m` as `\x1b[1mbob.testnet\x1b[0m`, reading the actions from stdin
+  $ nearx batch \x1b[1;3m-\x1b[0m on \x1b[1;3m\"greeter.testnet\"\x1b[0m as \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m <<\x1b[1;3mEOF\x1b[0m
+    \x1b[1;3m[\x1b[0m
+      \x1b[1;3m{ \"action\": \"call\", \"method\": \"addGreeting\", \"args\": [\"Hello\"], \"gas\": \"50Tgas\" },\x1b[0m
+      \x1b[1;3m{ \"action\": \"call\", \"method\": \"addGreeting\", \"args\": [\"World\"], \"deposit\": \"0.1N\" }\x1b[0m
+    \x1b[1;3m]\x1b[0m
+  \x1b[1;3mEOF\x1b[0m
+
+  # Supported actions:
+  #   { \"action\": \"create_account\" }
+  #   { \"action\": \"deploy\", \"wasm\": \"<path>\" }
+  #   { \"action\": \"call\", \"method\": \"<method>\", [\"args\": <json>], [\"gas\": <gas>], [\"deposit\": <amount>] }
+  #   { \"action\": \"transfer\", \"amount\": <amount> }
+  #   { \"action\": \"add_key\", \"public_key\": \"<key>\", [\"receiver_id\": \"<contract>\", [\"method_names\": [..]], [\"allowance\": <amount>]] }
+  #   { \"action\": \"delete_key\", \"public_key\": \"<key>\" }
+  #   { \"action\": \"delete_account\", \"beneficiary_id\": \"<account>\" }
+  #
+  # Gas and amounts are strings with a unit, like \x1b[1m\"30Tgas\"\x1b[0m and \x1b[1m\"0.1N\"\x1b[0m. Gas may also be a number of gas units.
+  # Calls without explicit gas get the network's default gas, or else evenly share what's left of the \x1b[1m300 TGas\x1b[0m limit.";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1mbatch \x1b[3mACTIONS\x1b[0m [on \x1b[3mRECEIVER\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Sends a transaction composed of multiple actions
+pub struct BatchCommand {
+    /// JSON list of actions to execute atomically, or `-` to read it from stdin.
+    #[clap(value_name = "ACTIONS")]
+    actions: String,
+
+    #[clap(subcommand)]
+    rest: Option<BatchCommandRest>,
+}
+
+impl BatchCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.actions = Some(self.actions);
+        match self.rest {
+            Some(BatchCommandRest::On(on_receiver)) => on_receiver.apply(command),
+            Some(BatchCommandRest::As(with_signer)) => with_signer.apply(command),
+            Some(BatchCommandRest::Display(display)) => display.apply(command),
+            Some(BatchCommandRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BatchCommandRest {
+    On(BatchCommandRestOn),
+    As(BatchCommandAs),
+    Display(BatchCommandDisplay),
+    Through(BatchCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx batch \x1b[3mACTIONS\x1b[0m \x1b[1mon \x1b[3mRECEIVER\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to send the actions to. (Defaults to the signer.)
+struct BatchCommandRestOn {
+    /// The account to send the actions to.
+    receiver: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<BatchCommandRestOnRest>,
+}
+
+impl BatchCommandRestOn {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.receiver_id = Some(self.receiver);
+        match self.rest {
+            Some(BatchCommandRestOnRest::As(with_signer)) => with_signer.apply(command),
+            Some(BatchCommandRestOnRest::Display(display)) => display.apply(command),
+            Some(BatchCommandRestOnRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BatchCommandRestOnRest {
+    As(BatchCommandAs),
+    Display(BatchCommandDisplay),
+    Through(BatchCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx batch \x1b[3mACTIONS\x1b[0m [on \x1b[3mRECEIVER\x1b[0m] \
+    \x1b[1mas \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to sign the transaction as.
+struct BatchCommandAs {
+    /// The account to sign the transaction as. (Defaults to the signer of the network.)
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<BatchCommandAsRest>,
+}
+
+impl BatchCommandAs {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.signer_id = Some(self.account);
+        match self.rest {
+            Some(BatchCommandAsRest::With(with_secret_key)) => with_secret_key.apply(command),
+            Some(BatchCommandAsRest::Display(display)) => display.apply(command),
+            Some(BatchCommandAsRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BatchCommandAsRest {
+    With(BatchCommandAsRestWith),
+    Display(BatchCommandDisplay),
+    Through(BatchCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx batch \x1b[3mACTIONS\x1b[0m [on \x1b[3mRECEIVER\x1b[0m] \
+    as \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the secret key to use.
+struct BatchCommandAsRestWith {
+    /// The private key or seed phrase to use, or a file containing the key. [env: NEAR_SECRET_KEY]
+    /// Format: `<ed25519|secp256k1>:<bs58 private key>`
+    #[clap(verbatim_doc_comment, value_parser = super::call::secret_key_from_str)]
+    private_key: near_crypto::SecretKey,
+
+    #[clap(subcommand)]
+    rest: Option<BatchCommandAsRestWithRest>,
+}
+
+impl BatchCommandAsRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.secret_key = Some(self.private_key);
+        match self.rest {
+            Some(BatchCommandAsRestWithRest::Display(display)) => display.apply(command),
+            Some(BatchCommandAsRestWithRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BatchCommandAsRestWithRest {
+    Display(BatchCommandDisplay),
+    Through(BatchCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx batch \x1b[3mACTIONS\x1b[0m [on \x1b[3mRECEIVER\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \x1b[1mdisplay\x1b[0m [offline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Displays the transaction that would be sent.
+struct BatchCommandDisplay {
+    #[clap(subcommand)]
+    rest: Option<BatchCommandDisplayRest>,
+}
+
+impl BatchCommandDisplay {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Display;
+        match self.rest {
+            Some(BatchCommandDisplayRest::Offline(offline)) => offline.apply(command),
+            Some(BatchCommandDisplayRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BatchCommandDisplayRest {
+    Offline(BatchCommandDisplayOffline),
+    Through(BatchCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx batch \x1b[3mACTIONS\x1b[0m [on \x1b[3mRECEIVER\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] display \x1b[1moffline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Signs the transaction without connecting to an RPC.
+struct BatchCommandDisplayOffline {
+    /// The nonce to sign the transaction with. (The access key's current nonce + 1.)
+    nonce: near_primitives::types::Nonce,
+
+    /// A recent block hash, or a file containing one.
+    #[clap(value_parser = super::block_hash_from_str)]
+    block_hash: near_primitives::hash::CryptoHash,
+}
+
+impl BatchCommandDisplayOffline {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Offline {
+            nonce: self.nonce,
+            block_hash: self.block_hash,
+        };
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx batch \x1b[3mACTIONS\x1b[0m [on \x1b[3mRECEIVER\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct BatchCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<BatchCommandThroughRest>,
+}
+
+impl BatchCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(BatchCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BatchCommandThroughRest {
+    With(BatchCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx batch \x1b[3mACTIONS\x1b[0m [on \x1b[3mRECEIVER\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct BatchCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl BatchCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+/// A single action in a batch, as accepted on the command line.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum BatchAction {
+    CreateAccount,
+    Deploy {
+        wasm: PathBuf,
+    },
+    Call {
+        method: String,
+        #[serde(default = "empty_args")]
+        args: serde_json::Value,
+        #[serde(default, deserialize_with = "deserialize_gas")]
+        gas: Option<near_primitives::types::Gas>,
+        #[serde(default, deserialize_with = "deserialize_optional_balance")]
+        deposit: Option<near_primitives::types::Balance>,
+    },
+    Transfer {
+        #[serde(deserialize_with = "deserialize_balance")]
+        amount: near_primitives::types::Balance,
+    },
+    AddKey {
+        public_key: near_crypto::PublicKey,
+        receiver_id: Option<AccountId>,
+        #[serde(default)]
+        method_names: Vec<String>,
+        #[serde(default, deserialize_with = "deserialize_optional_balance")]
+        allowance: Option<near_primitives::types::Balance>,
+    },
+    DeleteKey {
+        public_key: near_crypto::PublicKey,
+    },
+    DeleteAccount {
+        beneficiary_id: AccountId,
+    },
+}
+
+impl BatchAction {
+    pub fn parse_list(actions: &str) -> Result<Vec<Self>, serde_json::Error> {
+        serde_json::from_str(actions)
+    }
+}
+
+fn empty_args() -> serde_json::Value {
+    serde_json::json!({})
+}
+
+/// A quoted amount with a unit, or a bare number, which only gas accepts.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAmount {
+    Units(u64),
+    Amount(String),
+    Invalid(serde::de::IgnoredAny),
+}
+
+fn deserialize_gas<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<near_primitives::types::Gas>, D::Error> {
+    match RawAmount::deserialize(deserializer)? {
+        RawAmount::Units(gas) => Ok(Some(gas)),
+        RawAmount::Amount(gas) => utils::parse_gas(&gas)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        RawAmount::Invalid(_) => Err(serde::de::Error::custom(
+            "expected gas like \"30Tgas\", or a number of gas units",
+        )),
+    }
+}
+
+fn deserialize_balance<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<near_primitives::types::Balance, D::Error> {
+    match RawAmount::deserialize(deserializer)? {
+        // rejected for lacking a unit, like on the command line
+        RawAmount::Units(balance) => {
+            utils::parse_balance(&balance.to_string()).map_err(serde::de::Error::custom)
+        }
+        RawAmount::Amount(balance) => {
+            utils::parse_balance(&balance).map_err(serde::de::Error::custom)
+        }
+        RawAmount::Invalid(_) => Err(serde::de::Error::custom("expected an amount like \"0.1N\"")),
+    }
+}
+
+fn deserialize_optional_balance<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<near_primitives::types::Balance>, D::Error> {
+    deserialize_balance(deserializer).map(Some)
+}
+
+pub struct ConsumableCommand {
+    pub actions: Option<String>,
+    pub receiver_id: Option<AccountId>,
+    pub signer_id: Option<AccountId>,
+    pub secret_key: Option<near_crypto::SecretKey>,
+    pub action: CallAction,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+}
+
+impl Default for ConsumableCommand {
+    fn default() -> Self {
+        Self {
+            actions: None,
+            receiver_id: None,
+            signer_id: None,
+            secret_key: None,
+            action: CallAction::Submit,
+            rpc_url: None,
+            rpc_api_key: None,
+        }
+    }
+}
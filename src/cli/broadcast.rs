@@ -0,0 +1,152 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+pub const EXAMPLES: &str = "
+  # Broadcast a signed transaction and wait for its outcome
+  $ nearx broadcast \x1b[1;3m\"DAAAAG1pcmFj..RiFN4/m1WxBA==\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Broadcast a signed transaction read from stdin without waiting for it to be executed
+  $ nearx broadcast \x1b[1;3m-\x1b[0m async through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m <<<\x1b[1;3m\"DAAAAG1pcmFj..RiFN4/m1WxBA==\"\x1b[0m";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1mbroadcast \x1b[3mSIGNED_TX\x1b[0m [async] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Broadcasts a signed transaction
+pub struct BroadcastCommand {
+    /// A base-64 encoded, borsh-compacted, NEAR signed transaction, or `-` to read it from stdin.
+    #[clap(value_name = "SIGNED_TX")]
+    signed_tx: String,
+
+    #[clap(subcommand)]
+    rest: Option<BroadcastCommandRest>,
+}
+
+impl BroadcastCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.signed_tx = Some(self.signed_tx);
+        match self.rest {
+            Some(BroadcastCommandRest::Async(no_wait)) => no_wait.apply(command),
+            Some(BroadcastCommandRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BroadcastCommandRest {
+    Async(BroadcastCommandAsync),
+    Through(BroadcastCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx broadcast \x1b[3mSIGNED_TX\x1b[0m \x1b[1masync\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Returns as soon as the transaction is accepted, without waiting for it to be executed.
+struct BroadcastCommandAsync {
+    #[clap(subcommand)]
+    rest: Option<BroadcastCommandAsyncRest>,
+}
+
+impl BroadcastCommandAsync {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.mode = BroadcastMode::Async;
+        match self.rest {
+            Some(BroadcastCommandAsyncRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BroadcastCommandAsyncRest {
+    Through(BroadcastCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx broadcast \x1b[3mSIGNED_TX\x1b[0m [async] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct BroadcastCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<BroadcastCommandThroughRest>,
+}
+
+impl BroadcastCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(BroadcastCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum BroadcastCommandThroughRest {
+    With(BroadcastCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx broadcast \x1b[3mSIGNED_TX\x1b[0m [async] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct BroadcastCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl BroadcastCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+#[derive(Debug)]
+pub enum BroadcastMode {
+    Commit,
+    Async,
+}
+
+pub struct ConsumableCommand {
+    pub signed_tx: Option<String>,
+    pub mode: BroadcastMode,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+}
+
+impl Default for ConsumableCommand {
+    fn default() -> Self {
+        Self {
+            signed_tx: None,
+            mode: BroadcastMode::Commit,
+            rpc_url: None,
+            rpc_api_key: None,
+        }
+    }
+}
@@ -19,6 +19,9 @@ pub const EXAMPLES: &str = "
   # Read `\x1b[1mNEAR_RPC_URL\x1b[0m` and `\x1b[1mNEAR_RPC_API_KEY\x1b[0m` from the environment
   $ nearx call \x1b[1;3m\"add\"\x1b[0m with \x1b[1;3m\"[1, 2]\"\x1b[0m on \x1b[1;3m\"adder.testnet\"\x1b[0m
 
+  # Immutably call `\x1b[1mget_greeting()\x1b[0m` on `\x1b[1mgreeter.testnet\x1b[0m` as of block `\x1b[1m112233\x1b[0m`, on an archival node
+  $ nearx call \x1b[1;3m\"get_greeting\"\x1b[0m on \x1b[1;3m\"greeter.testnet\"\x1b[0m at \x1b[1;3m112233\x1b[0m through \x1b[1;3m\"https://archival-rpc.testnet.near.org\"\x1b[0m
+
   # Display a transaction that calls `\x1b[1maddGreeting(\"Hello, World!\")\x1b[0m` on `\x1b[1mgreeter.testnet\x1b[0m` as `\x1b[1mderek.testnet\x1b[0m` with `\x1b[1m100 TGas\x1b[0m` and `\x1b[1m5 Ⓝ\x1b[0m` deposit.
   $ nearx call \x1b[1;3m\"addGreeting\"\x1b[0m with \x1b[1;3m'[\"Hello World\"]'\x1b[0m on \x1b[1;3m\"greeter.testnet\"\x1b[0m as \x1b[1;3m\"derek.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m gas \x1b[1;3m\"100Tgas\"\x1b[0m deposit \x1b[1;3m\"5N\"\x1b[0m display";
 
@@ -27,9 +30,9 @@ pub const EXAMPLES: &str = "
     "\x1b[1;4mHint:\x1b[0m\n  \
     nearx \x1b[1mcall \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
-    on \x1b[3mCONTRACT\x1b[0m \
-    [as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    on \x1b[3mCONTRACT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
@@ -38,6 +41,10 @@ pub struct CallCommand {
     /// Defines the method to call.
     method: String,
 
+    /// Print the outcome as a single JSON document
+    #[clap(long, global = true)]
+    json: bool,
+
     #[clap(subcommand)]
     rest: CallCommandRest,
 }
@@ -45,6 +52,7 @@ pub struct CallCommand {
 impl CallCommand {
     pub fn apply(self, command: &mut ConsumableCommand) {
         command.method = Some(self.method);
+        command.json = self.json;
         match self.rest {
             CallCommandRest::With(with_args) => with_args.apply(command),
             CallCommandRest::On(on_contract) => on_contract.apply(command),
@@ -63,9 +71,9 @@ enum CallCommandRest {
     "\x1b[1;4mHint:\x1b[0m\n  \
     nearx call \
     \x1b[3mMETHOD\x1b[0m \x1b[1mwith \x1b[3m'{}'\x1b[0m \
-    on \x1b[3mCONTRACT\x1b[0m \
-    [as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    on \x1b[3mCONTRACT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
@@ -87,7 +95,7 @@ impl CallCommandRestWith {
     }
 }
 
-fn serde_json_from_str(s: &str) -> Result<serde_json::Value, serde_json::Error> {
+pub(super) fn serde_json_from_str(s: &str) -> Result<serde_json::Value, serde_json::Error> {
     serde_json::from_str(s)
 }
 
@@ -101,9 +109,9 @@ enum CallCommandRestWithRest {
     "\x1b[1;4mHint:\x1b[0m\n  \
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
-    \x1b[1mon \x1b[3mCONTRACT\x1b[0m \
-    [as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    \x1b[1mon \x1b[3mCONTRACT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
@@ -120,6 +128,7 @@ impl CallCommandRestOn {
     fn apply(self, command: &mut ConsumableCommand) {
         command.contract_id = Some(self.contract);
         match self.rest {
+            Some(CallCommandRestOnRest::At(at_block)) => at_block.apply(command),
             Some(CallCommandRestOnRest::As(with_signer)) => with_signer.apply(command),
             Some(CallCommandRestOnRest::Through(through_rpc)) => through_rpc.apply(command),
             None => {}
@@ -129,18 +138,55 @@ impl CallCommandRestOn {
 
 #[derive(Debug, Subcommand)]
 enum CallCommandRestOnRest {
+    At(CallCommandRestOnRestAt),
     As(CallCommandRestOnRestAs),
     Through(CallCommandThrough),
 }
 
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx call \
+    \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
+    on \x1b[3mCONTRACT\x1b[0m \x1b[1mat \x1b[3mBLOCK\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the block to call the contract at. (Only for immutable calls.)
+struct CallCommandRestOnRestAt {
+    /// The block to call the contract at.
+    /// Format: `<height|hash|final|near-final|optimistic>`, e.g. `112233`, `final`
+    #[clap(verbatim_doc_comment, value_name = "BLOCK", value_parser = block_reference_from_str)]
+    block: near_primitives::types::BlockReference,
+
+    #[clap(subcommand)]
+    rest: Option<CallCommandRestOnRestAtRest>,
+}
+
+impl CallCommandRestOnRestAt {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.block = self.block;
+        match self.rest {
+            Some(CallCommandRestOnRestAtRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CallCommandRestOnRestAtRest {
+    Through(CallCommandThrough),
+}
+
 #[derive(Debug, Parser)]
 #[clap(after_help = concatcp!(
     "\x1b[1;4mHint:\x1b[0m\n  \
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
     on \x1b[3mCONTRACT\x1b[0m \
-    \x1b[1mas \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    \x1b[1mas \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
@@ -150,20 +196,32 @@ struct CallCommandRestOnRestAs {
     account: AccountId,
 
     #[clap(subcommand)]
-    rest: CallCommandRestOnRestAsRest,
+    rest: Option<CallCommandRestOnRestAsRest>,
 }
 
 impl CallCommandRestOnRestAs {
     fn apply(self, command: &mut ConsumableCommand) {
         command.signer_id = Some(self.account);
-        let CallCommandRestOnRestAsRest::With(with_secret_key) = self.rest;
-        with_secret_key.apply(command);
+        match self.rest {
+            Some(CallCommandRestOnRestAsRest::With(with_secret_key)) => {
+                with_secret_key.apply(command)
+            }
+            Some(CallCommandRestOnRestAsRest::Gas(gas)) => gas.apply(command),
+            Some(CallCommandRestOnRestAsRest::Deposit(deposit)) => deposit.apply(command),
+            Some(CallCommandRestOnRestAsRest::Display(display)) => display.apply(command),
+            Some(CallCommandRestOnRestAsRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
     }
 }
 
 #[derive(Debug, Subcommand)]
 enum CallCommandRestOnRestAsRest {
     With(CallCommandRestOnRestAsRestWith),
+    Gas(CallCommandRestOnRestAsRestWithRestGas),
+    Deposit(CallCommandRestOnRestAsRestWithRestDeposit),
+    Display(CallCommandDisplay),
+    Through(CallCommandThrough),
 }
 
 #[derive(Debug, Parser)]
@@ -173,15 +231,15 @@ enum CallCommandRestOnRestAsRest {
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
     on \x1b[3mCONTRACT\x1b[0m \
     as \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
 /// Defines the secret key to use.
 struct CallCommandRestOnRestAsRestWith {
-    /// The private key to use.
+    /// The private key or seed phrase to use, or a file containing the key. [env: NEAR_SECRET_KEY]
     /// Format: `<ed25519|secp256k1>:<bs58 private key>`
-    #[clap(verbatim_doc_comment)]
+    #[clap(verbatim_doc_comment, value_parser = secret_key_from_str)]
     private_key: near_crypto::SecretKey,
 
     #[clap(subcommand)]
@@ -217,14 +275,16 @@ enum CallCommandRestOnRestAsRestWithRest {
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
     on \x1b[3mCONTRACT\x1b[0m \
-    as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m \x1b[1mgas \x1b[3mGAS\x1b[0m [deposit \x1b[3mDEPOSIT\x1b[0m] [display] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] \x1b[1mgas \x1b[3mGAS\x1b[0m [deposit \x1b[3mDEPOSIT\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
 /// Defines the amount of gas to use.
 struct CallCommandRestOnRestAsRestWithRestGas {
     /// The amount of gas to use.
+    /// Format: `<amount>[Tgas|Ggas|gas]`, e.g. `300Tgas`, `2.5 Tgas`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_gas)]
     gas: near_primitives::types::Gas,
 
     #[clap(subcommand)]
@@ -233,7 +293,7 @@ struct CallCommandRestOnRestAsRestWithRestGas {
 
 impl CallCommandRestOnRestAsRestWithRestGas {
     fn apply(self, command: &mut ConsumableCommand) {
-        command.gas = self.gas;
+        command.gas = Some(self.gas);
         match self.rest {
             Some(CallCommandRestOnRestAsRestWithRestGasRest::Deposit(deposit)) => {
                 deposit.apply(command)
@@ -262,14 +322,16 @@ enum CallCommandRestOnRestAsRestWithRestGasRest {
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
     on \x1b[3mCONTRACT\x1b[0m \
-    as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] \x1b[1mdeposit \x1b[3mDEPOSIT\x1b[0m [display] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] \x1b[1mdeposit \x1b[3mDEPOSIT\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
 /// Defines the amount of NEAR to deposit.
 struct CallCommandRestOnRestAsRestWithRestGasRestDeposit {
     /// The amount of NEAR to deposit.
+    /// Format: `<amount><NEAR|N|Ⓝ|milliNEAR|yoctoNEAR>`, e.g. `5N`, `0.25 NEAR`, `10yocto`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_balance)]
     deposit: near_primitives::types::Balance,
 
     #[clap(subcommand)]
@@ -303,14 +365,16 @@ enum CallCommandRestOnRestAsRestWithRestGasRestDepositRest {
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
     on \x1b[3mCONTRACT\x1b[0m \
-    as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m \x1b[1mdeposit \x1b[3mDEPOSIT\x1b[0m [gas \x1b[3mGAS\x1b[0m] [display] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] \x1b[1mdeposit \x1b[3mDEPOSIT\x1b[0m [gas \x1b[3mGAS\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
 /// Defines the amount of NEAR to deposit.
 struct CallCommandRestOnRestAsRestWithRestDeposit {
     /// The amount of NEAR to deposit.
+    /// Format: `<amount><NEAR|N|Ⓝ|milliNEAR|yoctoNEAR>`, e.g. `5N`, `0.25 NEAR`, `10yocto`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_balance)]
     deposit: near_primitives::types::Balance,
 
     #[clap(subcommand)]
@@ -346,14 +410,16 @@ enum CallCommandRestOnRestAsRestWithRestDepositRest {
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
     on \x1b[3mCONTRACT\x1b[0m \
-    as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [deposit \x1b[3mDEPOSIT\x1b[0m] \x1b[1mgas \x1b[3mGAS\x1b[0m [display] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \x1b[1mgas \x1b[3mGAS\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
 /// Defines the amount of gas to use.
 struct CallCommandRestOnRestAsRestWithRestDepositRestGas {
     /// The amount of gas to use.
+    /// Format: `<amount>[Tgas|Ggas|gas]`, e.g. `300Tgas`, `2.5 Tgas`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_gas)]
     gas: near_primitives::types::Gas,
 
     #[clap(subcommand)]
@@ -362,7 +428,7 @@ struct CallCommandRestOnRestAsRestWithRestDepositRestGas {
 
 impl CallCommandRestOnRestAsRestWithRestDepositRestGas {
     fn apply(self, command: &mut ConsumableCommand) {
-        command.gas = self.gas;
+        command.gas = Some(self.gas);
         match self.rest {
             Some(CallCommandRestOnRestAsRestWithRestDepositRestGasRest::Display(display)) => {
                 display.apply(command)
@@ -386,9 +452,9 @@ enum CallCommandRestOnRestAsRestWithRestDepositRestGasRest {
     "\x1b[1;4mHint:\x1b[0m\n  \
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
-    on \x1b[3mCONTRACT\x1b[0m \
-    [as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \x1b[1mdisplay\x1b[0m] \
-    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    on \x1b[3mCONTRACT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \x1b[1mdisplay\x1b[0m [offline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m]] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
@@ -402,6 +468,7 @@ impl CallCommandDisplay {
     fn apply(self, command: &mut ConsumableCommand) {
         command.action = CallAction::Display;
         match self.rest {
+            Some(CallCommandDisplayRest::Offline(offline)) => offline.apply(command),
             Some(CallCommandDisplayRest::Through(through_rpc)) => through_rpc.apply(command),
             None => {}
         }
@@ -410,6 +477,7 @@ impl CallCommandDisplay {
 
 #[derive(Debug, Subcommand)]
 enum CallCommandDisplayRest {
+    Offline(CallCommandDisplayOffline),
     Through(CallCommandThrough),
 }
 
@@ -419,14 +487,43 @@ enum CallCommandDisplayRest {
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
     on \x1b[3mCONTRACT\x1b[0m \
-    [as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
-    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] display \x1b[1moffline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Signs the transaction without connecting to an RPC.
+struct CallCommandDisplayOffline {
+    /// The nonce to sign the transaction with. (The access key's current nonce + 1.)
+    nonce: near_primitives::types::Nonce,
+
+    /// A recent block hash, or a file containing one.
+    #[clap(value_parser = super::block_hash_from_str)]
+    block_hash: near_primitives::hash::CryptoHash,
+}
+
+impl CallCommandDisplayOffline {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Offline {
+            nonce: self.nonce,
+            block_hash: self.block_hash,
+        };
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx call \
+    \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
+    on \x1b[3mCONTRACT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
 /// Defines the RPC URL to connect to.
 struct CallCommandThrough {
-    /// The RPC URL to connect to. [env: NEAR_RPC_URL]
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
     #[clap(value_name = "URL")]
     rpc_url: String,
 
@@ -454,17 +551,18 @@ enum CallCommandThroughRest {
     "\x1b[1;4mHint:\x1b[0m\n  \
     nearx call \
     \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \
-    on \x1b[3mCONTRACT\x1b[0m \
-    [as \x1b[3mACCOUNT\x1b[0m with \x1b[3mSECRET\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
-    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    on \x1b[3mCONTRACT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] [display]] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m [--json]",
     super::EXAMPLES_HEADER,
     EXAMPLES
 ))]
 /// Defines the RPC API key to use.
 struct CallCommandThroughRestWith {
-    /// The RPC API key to use. [env: NEAR_RPC_API_KEY]
-    #[clap(value_name = "KEY", value_parser = api_key_from_str)]
-    rpc_api_key: near_jsonrpc_client::auth::ApiKey,
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
 }
 
 impl CallCommandThroughRestWith {
@@ -473,8 +571,18 @@ impl CallCommandThroughRestWith {
     }
 }
 
-fn api_key_from_str(s: &str) -> Result<near_jsonrpc_client::auth::ApiKey, String> {
-    near_jsonrpc_client::auth::ApiKey::new(s).map_err(|e| e.to_string())
+pub(super) fn secret_key_from_str(s: &str) -> Result<near_crypto::SecretKey, String> {
+    crate::credentials::secret_key_from_str(s).map_err(|e| e.to_string())
+}
+
+pub(super) fn block_reference_from_str(
+    s: &str,
+) -> Result<near_primitives::types::BlockReference, String> {
+    super::block_reference_from_str(s).map_err(|e| e.to_string())
+}
+
+pub(super) fn auth_header_from_str(s: &str) -> Result<super::AuthHeader, String> {
+    super::AuthHeader::api_key(s).map_err(|e| e.to_string())
 }
 
 #[derive(Debug)]
@@ -489,6 +597,10 @@ pub struct AccountForTx {
 #[derive(Debug)]
 pub enum CallAction {
     Display,
+    Offline {
+        nonce: near_primitives::types::Nonce,
+        block_hash: near_primitives::hash::CryptoHash,
+    },
     Submit,
 }
 
@@ -498,11 +610,13 @@ pub struct ConsumableCommand {
     pub contract_id: Option<AccountId>,
     pub signer_id: Option<AccountId>,
     pub secret_key: Option<near_crypto::SecretKey>,
-    pub gas: near_primitives::types::Gas,
+    pub gas: Option<near_primitives::types::Gas>,
     pub deposit: near_primitives::types::Balance,
     pub action: CallAction,
+    pub block: near_primitives::types::BlockReference,
     pub rpc_url: Option<String>,
-    pub rpc_api_key: Option<near_jsonrpc_client::auth::ApiKey>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+    pub json: bool,
 }
 
 impl Default for ConsumableCommand {
@@ -513,11 +627,13 @@ impl Default for ConsumableCommand {
             contract_id: None,
             signer_id: None,
             secret_key: None,
-            gas: utils::TGAS * 300,
-            deposit: utils::NEAR * 0,
+            gas: None,
+            deposit: 0,
             action: CallAction::Submit,
+            block: near_primitives::types::BlockReference::latest(),
             rpc_url: None,
             rpc_api_key: None,
+            json: false,
         }
     }
 }
@@ -0,0 +1,372 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+use crate::utils;
+
+use super::CallAction;
+
+pub const EXAMPLES: &str = "
+  # Create `\x1b[1mtest.bob.testnet\x1b[0m` as `\x1b[1mbob.testnet\x1b[0m`, sharing its key, with `\x1b[1m1 Ⓝ\x1b[0m` initial balance
+  $ nearx create account \x1b[1;3m\"test.bob.testnet\"\x1b[0m deposit \x1b[1;3m\"1N\"\x1b[0m as \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Create `\x1b[1mtest.bob.testnet\x1b[0m` as `\x1b[1mbob.testnet\x1b[0m` with a new full access key
+  $ nearx create account \x1b[1;3m\"test.bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847\"\x1b[0m deposit \x1b[1;3m\"0.1N\"\x1b[0m as \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Display a transaction that funds an implicit account from `\x1b[1mderek.testnet\x1b[0m`
+  $ nearx create account \x1b[1;3m\"bb4f5b4c2e2fc7c2b6e2b0d8c9e8a5e7..ab01c2d3e4f5a6b7\"\x1b[0m deposit \x1b[1;3m\"1N\"\x1b[0m as \x1b[1;3m\"derek.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m display";
+
+#[derive(Debug, Subcommand)]
+pub enum CreateCommand {
+    Account(CreateAccountCommand),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create \x1b[1maccount \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Creates a new account
+pub struct CreateAccountCommand {
+    /// The account to create. Either a sub-account of the signer or an implicit account.
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<CreateAccountCommandRest>,
+}
+
+impl CreateAccountCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.new_account_id = Some(self.account);
+        match self.rest {
+            Some(CreateAccountCommandRest::With(with_public_key)) => with_public_key.apply(command),
+            Some(CreateAccountCommandRest::Deposit(deposit)) => deposit.apply(command),
+            Some(CreateAccountCommandRest::As(with_signer)) => with_signer.apply(command),
+            Some(CreateAccountCommandRest::Display(display)) => display.apply(command),
+            Some(CreateAccountCommandRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CreateAccountCommandRest {
+    With(CreateAccountCommandRestWith),
+    Deposit(CreateAccountCommandDeposit),
+    As(CreateAccountCommandAs),
+    Display(CreateAccountCommandDisplay),
+    Through(CreateAccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create account \x1b[3mNEW_ACCOUNT\x1b[0m \x1b[1mwith \x1b[3mPUBLIC_KEY\x1b[0m [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the full access key of the new account. (Defaults to the signer's public key.)
+struct CreateAccountCommandRestWith {
+    /// The public key to add to the new account.
+    /// Format: `<ed25519|secp256k1>:<bs58 public key>`
+    #[clap(verbatim_doc_comment)]
+    public_key: near_crypto::PublicKey,
+
+    #[clap(subcommand)]
+    rest: Option<CreateAccountCommandRestWithRest>,
+}
+
+impl CreateAccountCommandRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.public_key = Some(self.public_key);
+        match self.rest {
+            Some(CreateAccountCommandRestWithRest::Deposit(deposit)) => deposit.apply(command),
+            Some(CreateAccountCommandRestWithRest::As(with_signer)) => with_signer.apply(command),
+            Some(CreateAccountCommandRestWithRest::Display(display)) => display.apply(command),
+            Some(CreateAccountCommandRestWithRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CreateAccountCommandRestWithRest {
+    Deposit(CreateAccountCommandDeposit),
+    As(CreateAccountCommandAs),
+    Display(CreateAccountCommandDisplay),
+    Through(CreateAccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create account \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] \x1b[1mdeposit \x1b[3mDEPOSIT\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the initial balance of the new account.
+struct CreateAccountCommandDeposit {
+    /// The amount of NEAR to fund the new account with.
+    /// Format: `<amount><NEAR|N|Ⓝ|milliNEAR|yoctoNEAR>`, e.g. `5N`, `0.25 NEAR`, `10yocto`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_balance)]
+    deposit: near_primitives::types::Balance,
+
+    #[clap(subcommand)]
+    rest: Option<CreateAccountCommandDepositRest>,
+}
+
+impl CreateAccountCommandDeposit {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.deposit = self.deposit;
+        match self.rest {
+            Some(CreateAccountCommandDepositRest::As(with_signer)) => with_signer.apply(command),
+            Some(CreateAccountCommandDepositRest::Display(display)) => display.apply(command),
+            Some(CreateAccountCommandDepositRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CreateAccountCommandDepositRest {
+    As(CreateAccountCommandAs),
+    Display(CreateAccountCommandDisplay),
+    Through(CreateAccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create account \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    \x1b[1mas \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to create the new account as.
+struct CreateAccountCommandAs {
+    /// The account to create the new account as. (Defaults to the signer of the network.)
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<CreateAccountCommandAsRest>,
+}
+
+impl CreateAccountCommandAs {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.signer_id = Some(self.account);
+        match self.rest {
+            Some(CreateAccountCommandAsRest::With(with_secret_key)) => {
+                with_secret_key.apply(command)
+            }
+            Some(CreateAccountCommandAsRest::Display(display)) => display.apply(command),
+            Some(CreateAccountCommandAsRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CreateAccountCommandAsRest {
+    With(CreateAccountCommandAsRestWith),
+    Display(CreateAccountCommandDisplay),
+    Through(CreateAccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create account \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    as \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the secret key to use.
+struct CreateAccountCommandAsRestWith {
+    /// The private key or seed phrase to use, or a file containing the key. [env: NEAR_SECRET_KEY]
+    /// Format: `<ed25519|secp256k1>:<bs58 private key>`
+    #[clap(verbatim_doc_comment, value_parser = super::call::secret_key_from_str)]
+    private_key: near_crypto::SecretKey,
+
+    #[clap(subcommand)]
+    rest: Option<CreateAccountCommandAsRestWithRest>,
+}
+
+impl CreateAccountCommandAsRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.secret_key = Some(self.private_key);
+        match self.rest {
+            Some(CreateAccountCommandAsRestWithRest::Display(display)) => display.apply(command),
+            Some(CreateAccountCommandAsRestWithRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CreateAccountCommandAsRestWithRest {
+    Display(CreateAccountCommandDisplay),
+    Through(CreateAccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create account \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \x1b[1mdisplay\x1b[0m [offline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Displays the transaction that would be sent.
+struct CreateAccountCommandDisplay {
+    #[clap(subcommand)]
+    rest: Option<CreateAccountCommandDisplayRest>,
+}
+
+impl CreateAccountCommandDisplay {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Display;
+        match self.rest {
+            Some(CreateAccountCommandDisplayRest::Offline(offline)) => offline.apply(command),
+            Some(CreateAccountCommandDisplayRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CreateAccountCommandDisplayRest {
+    Offline(CreateAccountCommandDisplayOffline),
+    Through(CreateAccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create account \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] display \x1b[1moffline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Signs the transaction without connecting to an RPC.
+struct CreateAccountCommandDisplayOffline {
+    /// The nonce to sign the transaction with. (The access key's current nonce + 1.)
+    nonce: near_primitives::types::Nonce,
+
+    /// A recent block hash, or a file containing one.
+    #[clap(value_parser = super::block_hash_from_str)]
+    block_hash: near_primitives::hash::CryptoHash,
+}
+
+impl CreateAccountCommandDisplayOffline {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Offline {
+            nonce: self.nonce,
+            block_hash: self.block_hash,
+        };
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create account \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct CreateAccountCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<CreateAccountCommandThroughRest>,
+}
+
+impl CreateAccountCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(CreateAccountCommandThroughRest::With(with_api_key)) => {
+                with_api_key.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CreateAccountCommandThroughRest {
+    With(CreateAccountCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx create account \x1b[3mNEW_ACCOUNT\x1b[0m [with \x1b[3mPUBLIC_KEY\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m] \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct CreateAccountCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl CreateAccountCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+pub struct ConsumableCommand {
+    pub new_account_id: Option<AccountId>,
+    pub public_key: Option<near_crypto::PublicKey>,
+    pub deposit: near_primitives::types::Balance,
+    pub signer_id: Option<AccountId>,
+    pub secret_key: Option<near_crypto::SecretKey>,
+    pub action: CallAction,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+}
+
+impl Default for ConsumableCommand {
+    fn default() -> Self {
+        Self {
+            new_account_id: None,
+            public_key: None,
+            deposit: 0,
+            signer_id: None,
+            secret_key: None,
+            action: CallAction::Submit,
+            rpc_url: None,
+            rpc_api_key: None,
+        }
+    }
+}
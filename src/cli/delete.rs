@@ -0,0 +1,483 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+use super::CallAction;
+
+pub const EXAMPLES: &str = "
+  # Delete `\x1b[1mtest.bob.testnet\x1b[0m`, sending its remaining balance to `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx delete account \x1b[1;3m\"test.bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m beneficiary \x1b[1;3m\"bob.testnet\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Display a transaction that deletes `\x1b[1mtest.derek.testnet\x1b[0m`
+  $ nearx delete account \x1b[1;3m\"test.derek.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m beneficiary \x1b[1;3m\"derek.testnet\"\x1b[0m display
+
+  # Delete a key from `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx delete key \x1b[1;3m\"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847\"\x1b[0m from \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m";
+
+#[derive(Debug, Subcommand)]
+pub enum DeleteCommand {
+    Account(DeleteAccountCommand),
+    Key(DeleteKeyCommand),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete \x1b[1maccount \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] beneficiary \x1b[3mBENEFICIARY\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Deletes an account
+pub struct DeleteAccountCommand {
+    /// The account to delete.
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: DeleteAccountCommandRest,
+}
+
+impl DeleteAccountCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        match self.rest {
+            DeleteAccountCommandRest::With(with_secret_key) => with_secret_key.apply(command),
+            DeleteAccountCommandRest::Beneficiary(beneficiary) => beneficiary.apply(command),
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteAccountCommandRest {
+    With(DeleteAccountCommandRestWith),
+    Beneficiary(DeleteAccountCommandBeneficiary),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete account \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m beneficiary \x1b[3mBENEFICIARY\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the secret key to use.
+struct DeleteAccountCommandRestWith {
+    /// The private key or seed phrase to use, or a file containing the key. [env: NEAR_SECRET_KEY]
+    /// Format: `<ed25519|secp256k1>:<bs58 private key>`
+    #[clap(verbatim_doc_comment, value_parser = super::call::secret_key_from_str)]
+    private_key: near_crypto::SecretKey,
+
+    #[clap(subcommand)]
+    rest: DeleteAccountCommandRestWithRest,
+}
+
+impl DeleteAccountCommandRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.secret_key = Some(self.private_key);
+        let DeleteAccountCommandRestWithRest::Beneficiary(beneficiary) = self.rest;
+        beneficiary.apply(command);
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteAccountCommandRestWithRest {
+    Beneficiary(DeleteAccountCommandBeneficiary),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete account \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] \x1b[1mbeneficiary \x1b[3mBENEFICIARY\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to send the remaining balance to.
+struct DeleteAccountCommandBeneficiary {
+    /// The account to send the remaining balance to.
+    beneficiary: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<DeleteAccountCommandBeneficiaryRest>,
+}
+
+impl DeleteAccountCommandBeneficiary {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.beneficiary_id = Some(self.beneficiary);
+        match self.rest {
+            Some(DeleteAccountCommandBeneficiaryRest::Display(display)) => display.apply(command),
+            Some(DeleteAccountCommandBeneficiaryRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteAccountCommandBeneficiaryRest {
+    Display(DeleteAccountCommandDisplay),
+    Through(DeleteAccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete account \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] beneficiary \x1b[3mBENEFICIARY\x1b[0m \x1b[1mdisplay\x1b[0m [offline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Displays the transaction that would be sent.
+struct DeleteAccountCommandDisplay {
+    #[clap(subcommand)]
+    rest: Option<DeleteAccountCommandDisplayRest>,
+}
+
+impl DeleteAccountCommandDisplay {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Display;
+        match self.rest {
+            Some(DeleteAccountCommandDisplayRest::Offline(offline)) => offline.apply(command),
+            Some(DeleteAccountCommandDisplayRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteAccountCommandDisplayRest {
+    Offline(DeleteAccountCommandDisplayOffline),
+    Through(DeleteAccountCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete account \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] beneficiary \x1b[3mBENEFICIARY\x1b[0m display \x1b[1moffline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Signs the transaction without connecting to an RPC.
+struct DeleteAccountCommandDisplayOffline {
+    /// The nonce to sign the transaction with. (The access key's current nonce + 1.)
+    nonce: near_primitives::types::Nonce,
+
+    /// A recent block hash, or a file containing one.
+    #[clap(value_parser = super::block_hash_from_str)]
+    block_hash: near_primitives::hash::CryptoHash,
+}
+
+impl DeleteAccountCommandDisplayOffline {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Offline {
+            nonce: self.nonce,
+            block_hash: self.block_hash,
+        };
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete account \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] beneficiary \x1b[3mBENEFICIARY\x1b[0m [display] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct DeleteAccountCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<DeleteAccountCommandThroughRest>,
+}
+
+impl DeleteAccountCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(DeleteAccountCommandThroughRest::With(with_api_key)) => {
+                with_api_key.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteAccountCommandThroughRest {
+    With(DeleteAccountCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete account \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] beneficiary \x1b[3mBENEFICIARY\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct DeleteAccountCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl DeleteAccountCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete \x1b[1mkey \x1b[3mPUBLIC_KEY\x1b[0m from \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Deletes an access key from an account
+pub struct DeleteKeyCommand {
+    /// The public key to delete.
+    /// Format: `<ed25519|secp256k1>:<bs58 public key>`
+    #[clap(verbatim_doc_comment)]
+    public_key: near_crypto::PublicKey,
+
+    #[clap(subcommand)]
+    rest: DeleteKeyCommandRest,
+}
+
+impl DeleteKeyCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.public_key = Some(self.public_key);
+        let DeleteKeyCommandRest::From(from_account) = self.rest;
+        from_account.apply(command);
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteKeyCommandRest {
+    From(DeleteKeyCommandRestFrom),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete key \x1b[3mPUBLIC_KEY\x1b[0m \x1b[1mfrom \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to delete the key from.
+struct DeleteKeyCommandRestFrom {
+    /// The account to delete the key from.
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<DeleteKeyCommandRestFromRest>,
+}
+
+impl DeleteKeyCommandRestFrom {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        match self.rest {
+            Some(DeleteKeyCommandRestFromRest::With(with_secret_key)) => {
+                with_secret_key.apply(command)
+            }
+            Some(DeleteKeyCommandRestFromRest::Display(display)) => display.apply(command),
+            Some(DeleteKeyCommandRestFromRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteKeyCommandRestFromRest {
+    With(DeleteKeyCommandRestFromRestWith),
+    Display(DeleteKeyCommandDisplay),
+    Through(DeleteKeyCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete key \x1b[3mPUBLIC_KEY\x1b[0m from \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the secret key to use.
+struct DeleteKeyCommandRestFromRestWith {
+    /// The private key or seed phrase to use, or a file containing the key. [env: NEAR_SECRET_KEY]
+    /// Format: `<ed25519|secp256k1>:<bs58 private key>`
+    #[clap(verbatim_doc_comment, value_parser = super::call::secret_key_from_str)]
+    private_key: near_crypto::SecretKey,
+
+    #[clap(subcommand)]
+    rest: Option<DeleteKeyCommandRestFromRestWithRest>,
+}
+
+impl DeleteKeyCommandRestFromRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.secret_key = Some(self.private_key);
+        match self.rest {
+            Some(DeleteKeyCommandRestFromRestWithRest::Display(display)) => display.apply(command),
+            Some(DeleteKeyCommandRestFromRestWithRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteKeyCommandRestFromRestWithRest {
+    Display(DeleteKeyCommandDisplay),
+    Through(DeleteKeyCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete key \x1b[3mPUBLIC_KEY\x1b[0m from \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] \x1b[1mdisplay\x1b[0m [offline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Displays the transaction that would be sent.
+struct DeleteKeyCommandDisplay {
+    #[clap(subcommand)]
+    rest: Option<DeleteKeyCommandDisplayRest>,
+}
+
+impl DeleteKeyCommandDisplay {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Display;
+        match self.rest {
+            Some(DeleteKeyCommandDisplayRest::Offline(offline)) => offline.apply(command),
+            Some(DeleteKeyCommandDisplayRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteKeyCommandDisplayRest {
+    Offline(DeleteKeyCommandDisplayOffline),
+    Through(DeleteKeyCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete key \x1b[3mPUBLIC_KEY\x1b[0m from \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] display \x1b[1moffline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Signs the transaction without connecting to an RPC.
+struct DeleteKeyCommandDisplayOffline {
+    /// The nonce to sign the transaction with. (The access key's current nonce + 1.)
+    nonce: near_primitives::types::Nonce,
+
+    /// A recent block hash, or a file containing one.
+    #[clap(value_parser = super::block_hash_from_str)]
+    block_hash: near_primitives::hash::CryptoHash,
+}
+
+impl DeleteKeyCommandDisplayOffline {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Offline {
+            nonce: self.nonce,
+            block_hash: self.block_hash,
+        };
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete key \x1b[3mPUBLIC_KEY\x1b[0m from \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct DeleteKeyCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<DeleteKeyCommandThroughRest>,
+}
+
+impl DeleteKeyCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(DeleteKeyCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeleteKeyCommandThroughRest {
+    With(DeleteKeyCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx delete key \x1b[3mPUBLIC_KEY\x1b[0m from \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct DeleteKeyCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl DeleteKeyCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+pub struct ConsumableCommand {
+    pub account_id: Option<AccountId>,
+    pub public_key: Option<near_crypto::PublicKey>,
+    pub secret_key: Option<near_crypto::SecretKey>,
+    pub beneficiary_id: Option<AccountId>,
+    pub action: CallAction,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+}
+
+impl Default for ConsumableCommand {
+    fn default() -> Self {
+        Self {
+            account_id: None,
+            public_key: None,
+            secret_key: None,
+            beneficiary_id: None,
+            action: CallAction::Submit,
+            rpc_url: None,
+            rpc_api_key: None,
+        }
+    }
+}
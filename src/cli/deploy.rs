@@ -0,0 +1,466 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+use serde_json::json;
+
+use near_account_id::AccountId;
+
+use crate::utils;
+
+use super::CallAction;
+
+pub const EXAMPLES: &str = "
+  # Deploy `\x1b[1mcontract.wasm\x1b[0m` to `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx deploy \x1b[1;3m\"contract.wasm\"\x1b[0m as \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Deploy a contract read from stdin to `\x1b[1mbob.testnet\x1b[0m` and call `\x1b[1mnew(\"Hello\")\x1b[0m` in the same transaction
+  $ nearx deploy \x1b[1;3m-\x1b[0m as \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m init \x1b[1;3m\"new\"\x1b[0m with \x1b[1;3m'[\"Hello\"]'\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m < \x1b[1;3mcontract.wasm\x1b[0m
+
+  # Display a transaction that deploys `\x1b[1mcontract.wasm\x1b[0m` to `\x1b[1mderek.testnet\x1b[0m` and calls `\x1b[1minit()\x1b[0m` with `\x1b[1m50 TGas\x1b[0m`
+  $ nearx deploy \x1b[1;3m\"contract.wasm\"\x1b[0m as \x1b[1;3m\"derek.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m init \x1b[1;3m\"init\"\x1b[0m gas \x1b[1;3m\"50Tgas\"\x1b[0m display";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1mdeploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Deploys a contract to an account
+pub struct DeployCommand {
+    /// Path to the `.wasm` file to deploy, or `-` to read it from stdin.
+    #[clap(value_name = "WASM")]
+    wasm: PathBuf,
+
+    #[clap(subcommand)]
+    rest: Option<DeployCommandRest>,
+}
+
+impl DeployCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.wasm = Some(self.wasm);
+        match self.rest {
+            Some(DeployCommandRest::As(with_signer)) => with_signer.apply(command),
+            Some(DeployCommandRest::Init(init)) => init.apply(command),
+            Some(DeployCommandRest::Display(display)) => display.apply(command),
+            Some(DeployCommandRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandRest {
+    As(DeployCommandRestAs),
+    Init(DeployCommandInit),
+    Display(DeployCommandDisplay),
+    Through(DeployCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    \x1b[1mas \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to deploy the contract to.
+struct DeployCommandRestAs {
+    /// The account to deploy the contract to. (Defaults to the signer of the network.)
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<DeployCommandRestAsRest>,
+}
+
+impl DeployCommandRestAs {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.signer_id = Some(self.account);
+        match self.rest {
+            Some(DeployCommandRestAsRest::With(with_secret_key)) => with_secret_key.apply(command),
+            Some(DeployCommandRestAsRest::Init(init)) => init.apply(command),
+            Some(DeployCommandRestAsRest::Display(display)) => display.apply(command),
+            Some(DeployCommandRestAsRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandRestAsRest {
+    With(DeployCommandRestAsRestWith),
+    Init(DeployCommandInit),
+    Display(DeployCommandDisplay),
+    Through(DeployCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    as \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the secret key to use.
+struct DeployCommandRestAsRestWith {
+    /// The private key or seed phrase to use, or a file containing the key. [env: NEAR_SECRET_KEY]
+    /// Format: `<ed25519|secp256k1>:<bs58 private key>`
+    #[clap(verbatim_doc_comment, value_parser = super::call::secret_key_from_str)]
+    private_key: near_crypto::SecretKey,
+
+    #[clap(subcommand)]
+    rest: Option<DeployCommandRestAsRestWithRest>,
+}
+
+impl DeployCommandRestAsRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.secret_key = Some(self.private_key);
+        match self.rest {
+            Some(DeployCommandRestAsRestWithRest::Init(init)) => init.apply(command),
+            Some(DeployCommandRestAsRestWithRest::Display(display)) => display.apply(command),
+            Some(DeployCommandRestAsRestWithRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandRestAsRestWithRest {
+    Init(DeployCommandInit),
+    Display(DeployCommandDisplay),
+    Through(DeployCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [\x1b[1minit \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines a method to call on the contract right after deployment.
+struct DeployCommandInit {
+    /// The method to call.
+    method: String,
+
+    #[clap(subcommand)]
+    rest: Option<DeployCommandInitRest>,
+}
+
+impl DeployCommandInit {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.init_method = Some(self.method);
+        match self.rest {
+            Some(DeployCommandInitRest::With(with_args)) => with_args.apply(command),
+            Some(DeployCommandInitRest::Gas(gas)) => gas.apply(command),
+            Some(DeployCommandInitRest::Deposit(deposit)) => deposit.apply(command),
+            Some(DeployCommandInitRest::Display(display)) => display.apply(command),
+            Some(DeployCommandInitRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandInitRest {
+    With(DeployCommandInitWith),
+    Gas(DeployCommandInitGas),
+    Deposit(DeployCommandInitDeposit),
+    Display(DeployCommandDisplay),
+    Through(DeployCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m \x1b[1mwith \x1b[3m'{}'\x1b[0m [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the JSON arguments to pass to the method.
+struct DeployCommandInitWith {
+    /// The JSON arguments to pass to the method.
+    #[clap(value_name = "JSON_ARGS", value_parser = super::call::serde_json_from_str)]
+    args: serde_json::Value,
+
+    #[clap(subcommand)]
+    rest: Option<DeployCommandInitWithRest>,
+}
+
+impl DeployCommandInitWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.init_args = self.args;
+        match self.rest {
+            Some(DeployCommandInitWithRest::Gas(gas)) => gas.apply(command),
+            Some(DeployCommandInitWithRest::Deposit(deposit)) => deposit.apply(command),
+            Some(DeployCommandInitWithRest::Display(display)) => display.apply(command),
+            Some(DeployCommandInitWithRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandInitWithRest {
+    Gas(DeployCommandInitGas),
+    Deposit(DeployCommandInitDeposit),
+    Display(DeployCommandDisplay),
+    Through(DeployCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] \x1b[1mgas \x1b[3mGAS\x1b[0m [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the amount of gas to use.
+struct DeployCommandInitGas {
+    /// The amount of gas to use.
+    /// Format: `<amount>[Tgas|Ggas|gas]`, e.g. `300Tgas`, `2.5 Tgas`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_gas)]
+    gas: near_primitives::types::Gas,
+
+    #[clap(subcommand)]
+    rest: Option<DeployCommandInitGasRest>,
+}
+
+impl DeployCommandInitGas {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.init_gas = Some(self.gas);
+        match self.rest {
+            Some(DeployCommandInitGasRest::Deposit(deposit)) => deposit.apply(command),
+            Some(DeployCommandInitGasRest::Display(display)) => display.apply(command),
+            Some(DeployCommandInitGasRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandInitGasRest {
+    Deposit(DeployCommandInitDeposit),
+    Display(DeployCommandDisplay),
+    Through(DeployCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] \x1b[1mdeposit \x1b[3mDEPOSIT\x1b[0m] \
+    [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the amount of NEAR to deposit.
+struct DeployCommandInitDeposit {
+    /// The amount of NEAR to deposit.
+    /// Format: `<amount><NEAR|N|Ⓝ|milliNEAR|yoctoNEAR>`, e.g. `5N`, `0.25 NEAR`, `10yocto`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_balance)]
+    deposit: near_primitives::types::Balance,
+
+    #[clap(subcommand)]
+    rest: Option<DeployCommandInitDepositRest>,
+}
+
+impl DeployCommandInitDeposit {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.init_deposit = self.deposit;
+        match self.rest {
+            Some(DeployCommandInitDepositRest::Display(display)) => display.apply(command),
+            Some(DeployCommandInitDepositRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandInitDepositRest {
+    Display(DeployCommandDisplay),
+    Through(DeployCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    \x1b[1mdisplay\x1b[0m [offline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Displays the transaction that would be sent.
+struct DeployCommandDisplay {
+    #[clap(subcommand)]
+    rest: Option<DeployCommandDisplayRest>,
+}
+
+impl DeployCommandDisplay {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Display;
+        match self.rest {
+            Some(DeployCommandDisplayRest::Offline(offline)) => offline.apply(command),
+            Some(DeployCommandDisplayRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandDisplayRest {
+    Offline(DeployCommandDisplayOffline),
+    Through(DeployCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    display \x1b[1moffline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Signs the transaction without connecting to an RPC.
+struct DeployCommandDisplayOffline {
+    /// The nonce to sign the transaction with. (The access key's current nonce + 1.)
+    nonce: near_primitives::types::Nonce,
+
+    /// A recent block hash, or a file containing one.
+    #[clap(value_parser = super::block_hash_from_str)]
+    block_hash: near_primitives::hash::CryptoHash,
+}
+
+impl DeployCommandDisplayOffline {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Offline {
+            nonce: self.nonce,
+            block_hash: self.block_hash,
+        };
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    [display] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct DeployCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<DeployCommandThroughRest>,
+}
+
+impl DeployCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(DeployCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum DeployCommandThroughRest {
+    With(DeployCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx deploy \x1b[3mWASM\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \
+    [init \x1b[3mMETHOD\x1b[0m [with \x1b[3m'{}'\x1b[0m] [gas \x1b[3mGAS\x1b[0m] [deposit \x1b[3mDEPOSIT\x1b[0m]] \
+    [display] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct DeployCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl DeployCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+pub struct ConsumableCommand {
+    pub wasm: Option<PathBuf>,
+    pub signer_id: Option<AccountId>,
+    pub secret_key: Option<near_crypto::SecretKey>,
+    pub init_method: Option<String>,
+    pub init_args: serde_json::Value,
+    pub init_gas: Option<near_primitives::types::Gas>,
+    pub init_deposit: near_primitives::types::Balance,
+    pub action: CallAction,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+}
+
+impl Default for ConsumableCommand {
+    fn default() -> Self {
+        Self {
+            wasm: None,
+            signer_id: None,
+            secret_key: None,
+            init_method: None,
+            init_args: json!({}),
+            init_gas: None,
+            init_deposit: 0,
+            action: CallAction::Submit,
+            rpc_url: None,
+            rpc_api_key: None,
+        }
+    }
+}
@@ -29,7 +29,7 @@ pub struct DissectCommand {
     pub json: bool,
 }
 
-fn signed_tx_from_str(s: &str) -> eyre::Result<SignedTransaction> {
+pub(super) fn signed_tx_from_str(s: &str) -> eyre::Result<SignedTransaction> {
     Ok(SignedTransaction::try_from_slice(
         &near_primitives::serialize::from_base64(s)?,
     )?)
@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+pub const EXAMPLES: &str = "
+  # Generate an ed25519 key pair and print its implicit account
+  $ nearx keygen
+
+  # Generate a secp256k1 key pair for `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx keygen \x1b[1;3msecp256k1\x1b[0m for \x1b[1;3m\"bob.testnet\"\x1b[0m
+
+  # Generate a key pair for `\x1b[1mbob.testnet\x1b[0m` and save it to `\x1b[1m~/.near-credentials/testnet/bob.testnet.json\x1b[0m`
+  $ nearx keygen for \x1b[1;3m\"bob.testnet\"\x1b[0m save
+
+  # Generate a key pair and save it to `\x1b[1mkey.json\x1b[0m`
+  $ nearx keygen save \x1b[1;3m\"key.json\"\x1b[0m";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1mkeygen\x1b[0m [\x1b[3mKEY_TYPE\x1b[0m] [for \x1b[3mACCOUNT\x1b[0m] [save [\x1b[3mPATH\x1b[0m]]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Generates a key pair
+pub struct KeygenCommand {
+    /// The type of key to generate.
+    #[clap(value_enum, default_value_t = KeyType::Ed25519)]
+    key_type: KeyType,
+
+    #[clap(subcommand)]
+    rest: Option<KeygenCommandRest>,
+}
+
+impl KeygenCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.key_type = self.key_type;
+        match self.rest {
+            Some(KeygenCommandRest::For(for_account)) => for_account.apply(command),
+            Some(KeygenCommandRest::Save(save)) => save.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum KeyType {
+    Ed25519,
+    Secp256k1,
+}
+
+impl From<KeyType> for near_crypto::KeyType {
+    fn from(key_type: KeyType) -> Self {
+        match key_type {
+            KeyType::Ed25519 => near_crypto::KeyType::ED25519,
+            KeyType::Secp256k1 => near_crypto::KeyType::SECP256K1,
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeygenCommandRest {
+    For(KeygenCommandFor),
+    Save(KeygenCommandSave),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keygen [\x1b[3mKEY_TYPE\x1b[0m] \x1b[1mfor \x1b[3mACCOUNT\x1b[0m [save [\x1b[3mPATH\x1b[0m]]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account the key is for.
+struct KeygenCommandFor {
+    /// The account the key is for. (Defaults to the implicit account of ed25519 keys.)
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<KeygenCommandForRest>,
+}
+
+impl KeygenCommandFor {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        match self.rest {
+            Some(KeygenCommandForRest::Save(save)) => save.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeygenCommandForRest {
+    Save(KeygenCommandSave),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keygen [\x1b[3mKEY_TYPE\x1b[0m] [for \x1b[3mACCOUNT\x1b[0m] \x1b[1msave\x1b[0m [\x1b[3mPATH\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Saves the key pair as a credentials file.
+struct KeygenCommandSave {
+    /// The file to save the key pair to.
+    /// (Defaults to `~/.near-credentials/<NETWORK>/<ACCOUNT>.json`)
+    #[clap(verbatim_doc_comment)]
+    path: Option<PathBuf>,
+}
+
+impl KeygenCommandSave {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.save = Some(self.path);
+    }
+}
+
+pub struct ConsumableCommand {
+    pub key_type: KeyType,
+    pub account_id: Option<AccountId>,
+    pub save: Option<Option<PathBuf>>,
+}
+
+impl Default for ConsumableCommand {
+    fn default() -> Self {
+        Self {
+            key_type: KeyType::Ed25519,
+            account_id: None,
+            save: None,
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+pub const EXAMPLES: &str = "
+  # List the access keys of `\x1b[1mbob.testnet\x1b[0m`, with their nonces and permissions
+  $ nearx keys \x1b[1;3m\"bob.testnet\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # List the access keys `\x1b[1mbob.near\x1b[0m` had at block `\x1b[1m112233\x1b[0m`, as JSON
+  $ nearx keys \x1b[1;3m\"bob.near\"\x1b[0m at \x1b[1;3m112233\x1b[0m through \x1b[1;3m\"https://archival-rpc.mainnet.near.org\"\x1b[0m --json
+
+  # Look up a single access key of `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx key \x1b[1;3m\"ed25519:AaYbuC8FFKGKGYdwRA9GYJ1RaqQVThKVWMzL4VD84SDJ\"\x1b[0m of \x1b[1;3m\"bob.testnet\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1mkeys \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Lists the access keys of an account
+pub struct KeysCommand {
+    /// The account to list the access keys of.
+    account: AccountId,
+
+    /// Print the access keys as a single JSON document
+    #[clap(long, global = true)]
+    json: bool,
+
+    #[clap(subcommand)]
+    rest: Option<KeysCommandRest>,
+}
+
+impl KeysCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        command.json = self.json;
+        match self.rest {
+            Some(KeysCommandRest::At(at_block)) => at_block.apply(command),
+            Some(KeysCommandRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeysCommandRest {
+    At(KeysCommandRestAt),
+    Through(KeysCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keys \x1b[3mACCOUNT\x1b[0m \x1b[1mat \x1b[3mBLOCK\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the block to list the access keys at.
+struct KeysCommandRestAt {
+    /// The block to list the access keys at.
+    /// Format: `<height|hash|final|near-final|optimistic>`, e.g. `112233`, `final`
+    #[clap(
+        verbatim_doc_comment,
+        value_name = "BLOCK",
+        value_parser = super::call::block_reference_from_str
+    )]
+    block: near_primitives::types::BlockReference,
+
+    #[clap(subcommand)]
+    rest: Option<KeysCommandRestAtRest>,
+}
+
+impl KeysCommandRestAt {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.block = Some(self.block);
+        match self.rest {
+            Some(KeysCommandRestAtRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeysCommandRestAtRest {
+    Through(KeysCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keys \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct KeysCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<KeysCommandThroughRest>,
+}
+
+impl KeysCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(KeysCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeysCommandThroughRest {
+    With(KeysCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keys \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct KeysCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl KeysCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1mkey \x1b[3mPUBLIC_KEY\x1b[0m of \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Looks up the nonce and permission of an access key
+pub struct KeyCommand {
+    /// The public key of the access key.
+    public_key: near_crypto::PublicKey,
+
+    /// Print the access key as a single JSON document
+    #[clap(long, global = true)]
+    json: bool,
+
+    #[clap(subcommand)]
+    rest: KeyCommandRest,
+}
+
+impl KeyCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.public_key = Some(self.public_key);
+        command.json = self.json;
+        let KeyCommandRest::Of(of_account) = self.rest;
+        of_account.apply(command);
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeyCommandRest {
+    Of(KeyCommandRestOf),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx key \x1b[3mPUBLIC_KEY\x1b[0m \x1b[1mof \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account the access key belongs to.
+struct KeyCommandRestOf {
+    /// The account the access key belongs to.
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<KeyCommandRestOfRest>,
+}
+
+impl KeyCommandRestOf {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        match self.rest {
+            Some(KeyCommandRestOfRest::At(at_block)) => at_block.apply(command),
+            Some(KeyCommandRestOfRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeyCommandRestOfRest {
+    At(KeyCommandRestOfRestAt),
+    Through(KeyCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx key \x1b[3mPUBLIC_KEY\x1b[0m of \x1b[3mACCOUNT\x1b[0m \x1b[1mat \x1b[3mBLOCK\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the block to look the access key up at.
+struct KeyCommandRestOfRestAt {
+    /// The block to look the access key up at.
+    /// Format: `<height|hash|final|near-final|optimistic>`, e.g. `112233`, `final`
+    #[clap(
+        verbatim_doc_comment,
+        value_name = "BLOCK",
+        value_parser = super::call::block_reference_from_str
+    )]
+    block: near_primitives::types::BlockReference,
+
+    #[clap(subcommand)]
+    rest: Option<KeyCommandRestOfRestAtRest>,
+}
+
+impl KeyCommandRestOfRestAt {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.block = Some(self.block);
+        match self.rest {
+            Some(KeyCommandRestOfRestAtRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeyCommandRestOfRestAtRest {
+    Through(KeyCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx key \x1b[3mPUBLIC_KEY\x1b[0m of \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct KeyCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<KeyCommandThroughRest>,
+}
+
+impl KeyCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(KeyCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeyCommandThroughRest {
+    With(KeyCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx key \x1b[3mPUBLIC_KEY\x1b[0m of \x1b[3mACCOUNT\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct KeyCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl KeyCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+#[derive(Default)]
+pub struct ConsumableCommand {
+    pub account_id: Option<AccountId>,
+    pub public_key: Option<near_crypto::PublicKey>,
+    pub block: Option<near_primitives::types::BlockReference>,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+    pub json: bool,
+}
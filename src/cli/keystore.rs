@@ -0,0 +1,277 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+pub const EXAMPLES: &str = "
+  # Encrypt a key for `\x1b[1mbob.testnet\x1b[0m`, prompting for it and for a passphrase
+  $ nearx keystore import \x1b[1;3m\"bob.testnet\"\x1b[0m
+
+  # Import the near-cli credentials of `\x1b[1mbob.near\x1b[0m` into the keystore
+  $ nearx keystore import \x1b[1;3m\"bob.near\"\x1b[0m with \x1b[1;3m~/.near-credentials/mainnet/bob.near.json\x1b[0m
+
+  # List the keys stored for testnet
+  $ nearx keystore list on \x1b[1;3mtestnet\x1b[0m
+
+  # Print the secret key of `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx keystore export \x1b[1;3m\"bob.testnet\"\x1b[0m
+
+  # Remove the key of `\x1b[1mbob.testnet\x1b[0m` from the keystore
+  $ nearx keystore remove \x1b[1;3m\"bob.testnet\"\x1b[0m";
+
+#[derive(Debug, Subcommand)]
+pub enum KeystoreCommand {
+    Import(KeystoreImportCommand),
+    List(KeystoreListCommand),
+    Export(KeystoreExportCommand),
+    Remove(KeystoreRemoveCommand),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore \x1b[1mimport \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [on \x1b[3mNETWORK\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Encrypts a key and stores it in the keystore
+pub struct KeystoreImportCommand {
+    /// The account the key is for.
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<KeystoreImportCommandRest>,
+}
+
+impl KeystoreImportCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        match self.rest {
+            Some(KeystoreImportCommandRest::With(with_secret_key)) => {
+                with_secret_key.apply(command)
+            }
+            Some(KeystoreImportCommandRest::On(on_network)) => on_network.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeystoreImportCommandRest {
+    With(KeystoreImportCommandRestWith),
+    On(KeystoreImportCommandOn),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore import \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m [on \x1b[3mNETWORK\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the secret key to import.
+struct KeystoreImportCommandRestWith {
+    /// The private key or seed phrase to import, or a file containing the key.
+    /// (Prompted for if omitted.)
+    /// Format: `<ed25519|secp256k1>:<bs58 private key>`
+    #[clap(verbatim_doc_comment, value_parser = super::call::secret_key_from_str)]
+    private_key: near_crypto::SecretKey,
+
+    #[clap(subcommand)]
+    rest: Option<KeystoreImportCommandRestWithRest>,
+}
+
+impl KeystoreImportCommandRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.secret_key = Some(self.private_key);
+        match self.rest {
+            Some(KeystoreImportCommandRestWithRest::On(on_network)) => on_network.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeystoreImportCommandRestWithRest {
+    On(KeystoreImportCommandOn),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore import \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] \x1b[1mon \x1b[3mNETWORK\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the network the account is on.
+struct KeystoreImportCommandOn {
+    /// The network the account is on. [env: NEAR_ENV]
+    /// (Defaults to the network of the account's top-level name.)
+    #[clap(verbatim_doc_comment)]
+    network: String,
+}
+
+impl KeystoreImportCommandOn {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.network = Some(self.network);
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore \x1b[1mlist\x1b[0m [on \x1b[3mNETWORK\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Lists the keys in the keystore
+pub struct KeystoreListCommand {
+    #[clap(subcommand)]
+    rest: Option<KeystoreListCommandRest>,
+}
+
+impl KeystoreListCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        match self.rest {
+            Some(KeystoreListCommandRest::On(on_network)) => on_network.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeystoreListCommandRest {
+    On(KeystoreListCommandOn),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore list \x1b[1mon \x1b[3mNETWORK\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the network to list the keys of.
+struct KeystoreListCommandOn {
+    /// The network to list the keys of. [env: NEAR_ENV]
+    /// (Defaults to all networks.)
+    #[clap(verbatim_doc_comment)]
+    network: String,
+}
+
+impl KeystoreListCommandOn {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.network = Some(self.network);
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore \x1b[1mexport \x1b[3mACCOUNT\x1b[0m [on \x1b[3mNETWORK\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Decrypts a key from the keystore and prints it
+pub struct KeystoreExportCommand {
+    /// The account to export the key of.
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<KeystoreExportCommandRest>,
+}
+
+impl KeystoreExportCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        match self.rest {
+            Some(KeystoreExportCommandRest::On(on_network)) => on_network.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeystoreExportCommandRest {
+    On(KeystoreExportCommandOn),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore export \x1b[3mACCOUNT\x1b[0m \x1b[1mon \x1b[3mNETWORK\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the network the account is on.
+struct KeystoreExportCommandOn {
+    /// The network the account is on. [env: NEAR_ENV]
+    /// (Defaults to the network of the account's top-level name.)
+    #[clap(verbatim_doc_comment)]
+    network: String,
+}
+
+impl KeystoreExportCommandOn {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.network = Some(self.network);
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore \x1b[1mremove \x1b[3mACCOUNT\x1b[0m [on \x1b[3mNETWORK\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Removes a key from the keystore
+pub struct KeystoreRemoveCommand {
+    /// The account to remove the key of.
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<KeystoreRemoveCommandRest>,
+}
+
+impl KeystoreRemoveCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.account_id = Some(self.account);
+        match self.rest {
+            Some(KeystoreRemoveCommandRest::On(on_network)) => on_network.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum KeystoreRemoveCommandRest {
+    On(KeystoreRemoveCommandOn),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx keystore remove \x1b[3mACCOUNT\x1b[0m \x1b[1mon \x1b[3mNETWORK\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the network the account is on.
+struct KeystoreRemoveCommandOn {
+    /// The network the account is on. [env: NEAR_ENV]
+    /// (Defaults to the network of the account's top-level name.)
+    #[clap(verbatim_doc_comment)]
+    network: String,
+}
+
+impl KeystoreRemoveCommandOn {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.network = Some(self.network);
+    }
+}
+
+#[derive(Default)]
+pub struct ConsumableCommand {
+    pub account_id: Option<AccountId>,
+    pub secret_key: Option<near_crypto::SecretKey>,
+    pub network: Option<String>,
+}
@@ -0,0 +1,314 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+use crate::utils;
+
+use super::CallAction;
+
+pub const EXAMPLES: &str = "
+  # Send `\x1b[1m5 Ⓝ\x1b[0m` from `\x1b[1mbob.testnet\x1b[0m` to `\x1b[1malice.testnet\x1b[0m`
+  $ nearx send \x1b[1;3m\"5N\"\x1b[0m to \x1b[1;3m\"alice.testnet\"\x1b[0m as \x1b[1;3m\"bob.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Display a transaction that sends `\x1b[1m0.25 Ⓝ\x1b[0m` from `\x1b[1mderek.testnet\x1b[0m` to `\x1b[1malice.testnet\x1b[0m`
+  $ nearx send \x1b[1;3m\"0.25 NEAR\"\x1b[0m to \x1b[1;3m\"alice.testnet\"\x1b[0m as \x1b[1;3m\"derek.testnet\"\x1b[0m with \x1b[1;3m\"ed25519:52CwWhWHzgaSZRx..bMFSyXn9hao4YNXuz\"\x1b[0m display
+
+  # Send `\x1b[1m1 Ⓝ\x1b[0m` to `\x1b[1malice.testnet\x1b[0m` from the default signer of the `\x1b[1mtestnet\x1b[0m` network
+  $ nearx send \x1b[1;3m\"1N\"\x1b[0m to \x1b[1;3m\"alice.testnet\"\x1b[0m through \x1b[1;3mtestnet\x1b[0m";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1msend \
+    \x1b[3mAMOUNT\x1b[0m to \x1b[3mRECEIVER\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Sends NEAR to an account
+pub struct SendCommand {
+    /// The amount of NEAR to send.
+    /// Format: `<amount><NEAR|N|Ⓝ|milliNEAR|yoctoNEAR>`, e.g. `5N`, `0.25 NEAR`, `10yocto`
+    #[clap(verbatim_doc_comment, value_parser = utils::parse_balance)]
+    amount: near_primitives::types::Balance,
+
+    #[clap(subcommand)]
+    rest: SendCommandRest,
+}
+
+impl SendCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.amount = Some(self.amount);
+        let SendCommandRest::To(to_receiver) = self.rest;
+        to_receiver.apply(command);
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum SendCommandRest {
+    To(SendCommandRestTo),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx send \
+    \x1b[3mAMOUNT\x1b[0m \x1b[1mto \x1b[3mRECEIVER\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to send NEAR to.
+struct SendCommandRestTo {
+    /// The account to send NEAR to.
+    receiver: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<SendCommandRestToRest>,
+}
+
+impl SendCommandRestTo {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.receiver_id = Some(self.receiver);
+        match self.rest {
+            Some(SendCommandRestToRest::As(with_signer)) => with_signer.apply(command),
+            Some(SendCommandRestToRest::Display(display)) => display.apply(command),
+            Some(SendCommandRestToRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum SendCommandRestToRest {
+    As(SendCommandRestToRestAs),
+    Display(SendCommandDisplay),
+    Through(SendCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx send \
+    \x1b[3mAMOUNT\x1b[0m to \x1b[3mRECEIVER\x1b[0m \
+    \x1b[1mas \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m] [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account to send NEAR from.
+struct SendCommandRestToRestAs {
+    /// The account to send NEAR from. (Defaults to the signer of the network.)
+    account: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<SendCommandRestToRestAsRest>,
+}
+
+impl SendCommandRestToRestAs {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.signer_id = Some(self.account);
+        match self.rest {
+            Some(SendCommandRestToRestAsRest::With(with_secret_key)) => {
+                with_secret_key.apply(command)
+            }
+            Some(SendCommandRestToRestAsRest::Display(display)) => display.apply(command),
+            Some(SendCommandRestToRestAsRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum SendCommandRestToRestAsRest {
+    With(SendCommandRestToRestAsRestWith),
+    Display(SendCommandDisplay),
+    Through(SendCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx send \
+    \x1b[3mAMOUNT\x1b[0m to \x1b[3mRECEIVER\x1b[0m \
+    as \x1b[3mACCOUNT\x1b[0m \x1b[1mwith \x1b[3mSECRET\x1b[0m [display] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the secret key to use.
+struct SendCommandRestToRestAsRestWith {
+    /// The private key or seed phrase to use, or a file containing the key. [env: NEAR_SECRET_KEY]
+    /// Format: `<ed25519|secp256k1>:<bs58 private key>`
+    #[clap(verbatim_doc_comment, value_parser = super::call::secret_key_from_str)]
+    private_key: near_crypto::SecretKey,
+
+    #[clap(subcommand)]
+    rest: Option<SendCommandRestToRestAsRestWithRest>,
+}
+
+impl SendCommandRestToRestAsRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.secret_key = Some(self.private_key);
+        match self.rest {
+            Some(SendCommandRestToRestAsRestWithRest::Display(display)) => display.apply(command),
+            Some(SendCommandRestToRestAsRestWithRest::Through(through_rpc)) => {
+                through_rpc.apply(command)
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum SendCommandRestToRestAsRestWithRest {
+    Display(SendCommandDisplay),
+    Through(SendCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx send \
+    \x1b[3mAMOUNT\x1b[0m to \x1b[3mRECEIVER\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] \x1b[1mdisplay\x1b[0m [offline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Displays the transaction that would be sent.
+struct SendCommandDisplay {
+    #[clap(subcommand)]
+    rest: Option<SendCommandDisplayRest>,
+}
+
+impl SendCommandDisplay {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Display;
+        match self.rest {
+            Some(SendCommandDisplayRest::Offline(offline)) => offline.apply(command),
+            Some(SendCommandDisplayRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum SendCommandDisplayRest {
+    Offline(SendCommandDisplayOffline),
+    Through(SendCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx send \
+    \x1b[3mAMOUNT\x1b[0m to \x1b[3mRECEIVER\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] display \x1b[1moffline \x1b[3mNONCE\x1b[0m \x1b[3mBLOCK_HASH\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Signs the transaction without connecting to an RPC.
+struct SendCommandDisplayOffline {
+    /// The nonce to sign the transaction with. (The access key's current nonce + 1.)
+    nonce: near_primitives::types::Nonce,
+
+    /// A recent block hash, or a file containing one.
+    #[clap(value_parser = super::block_hash_from_str)]
+    block_hash: near_primitives::hash::CryptoHash,
+}
+
+impl SendCommandDisplayOffline {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.action = CallAction::Offline {
+            nonce: self.nonce,
+            block_hash: self.block_hash,
+        };
+    }
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx send \
+    \x1b[3mAMOUNT\x1b[0m to \x1b[3mRECEIVER\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct SendCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<SendCommandThroughRest>,
+}
+
+impl SendCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(SendCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum SendCommandThroughRest {
+    With(SendCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx send \
+    \x1b[3mAMOUNT\x1b[0m to \x1b[3mRECEIVER\x1b[0m \
+    [as \x1b[3mACCOUNT\x1b[0m [with \x1b[3mSECRET\x1b[0m]] [display] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct SendCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl SendCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+pub struct ConsumableCommand {
+    pub amount: Option<near_primitives::types::Balance>,
+    pub receiver_id: Option<AccountId>,
+    pub signer_id: Option<AccountId>,
+    pub secret_key: Option<near_crypto::SecretKey>,
+    pub action: CallAction,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+}
+
+impl Default for ConsumableCommand {
+    fn default() -> Self {
+        Self {
+            amount: None,
+            receiver_id: None,
+            signer_id: None,
+            secret_key: None,
+            action: CallAction::Submit,
+            rpc_url: None,
+            rpc_api_key: None,
+        }
+    }
+}
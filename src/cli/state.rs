@@ -0,0 +1,233 @@
+use clap::{Parser, Subcommand};
+use color_eyre::eyre;
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+pub const EXAMPLES: &str = "
+  # Dump the storage of `\x1b[1mcounter.testnet\x1b[0m`
+  $ nearx state \x1b[1;3m\"counter.testnet\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m
+
+  # Dump the keys of `\x1b[1mapp.near\x1b[0m` starting with `\x1b[1mSTATE\x1b[0m` at block `\x1b[1m112233\x1b[0m`, as JSON
+  $ nearx state \x1b[1;3m\"app.near\"\x1b[0m prefix \x1b[1;3m\"STATE\"\x1b[0m at \x1b[1;3m112233\x1b[0m through \x1b[1;3m\"https://archival-rpc.mainnet.near.org\"\x1b[0m --json
+
+  # Dump the keys of `\x1b[1mapp.testnet\x1b[0m` starting with the bytes `\x1b[1m0x00 0x01\x1b[0m`
+  $ nearx state \x1b[1;3m\"app.testnet\"\x1b[0m prefix \x1b[1;3m0x0001\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1mstate \x1b[3mCONTRACT\x1b[0m [prefix \x1b[3mPREFIX\x1b[0m] [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Dumps the raw storage of a contract
+pub struct StateCommand {
+    /// The contract to dump the storage of.
+    contract: AccountId,
+
+    /// Print the storage as a single JSON document, with keys and values in base64
+    #[clap(long, global = true)]
+    json: bool,
+
+    #[clap(subcommand)]
+    rest: Option<StateCommandRest>,
+}
+
+impl StateCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.contract_id = Some(self.contract);
+        command.json = self.json;
+        match self.rest {
+            Some(StateCommandRest::Prefix(prefix)) => prefix.apply(command),
+            Some(StateCommandRest::At(at_block)) => at_block.apply(command),
+            Some(StateCommandRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum StateCommandRest {
+    Prefix(StateCommandRestPrefix),
+    At(StateCommandRestAt),
+    Through(StateCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx state \x1b[3mCONTRACT\x1b[0m \x1b[1mprefix \x1b[3mPREFIX\x1b[0m [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the prefix of the keys to dump.
+struct StateCommandRestPrefix {
+    /// Only dump the keys starting with this prefix.
+    /// Format: a string, or bytes in hex prefixed with `0x`, e.g. `STATE`, `0x0001`
+    #[clap(verbatim_doc_comment, value_name = "PREFIX", value_parser = prefix_from_str)]
+    prefix: near_primitives::types::StoreKey,
+
+    #[clap(subcommand)]
+    rest: Option<StateCommandRestPrefixRest>,
+}
+
+impl StateCommandRestPrefix {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.prefix = Some(self.prefix);
+        match self.rest {
+            Some(StateCommandRestPrefixRest::At(at_block)) => at_block.apply(command),
+            Some(StateCommandRestPrefixRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum StateCommandRestPrefixRest {
+    At(StateCommandRestAt),
+    Through(StateCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx state \x1b[3mCONTRACT\x1b[0m [prefix \x1b[3mPREFIX\x1b[0m] \x1b[1mat \x1b[3mBLOCK\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the block to dump the storage at.
+struct StateCommandRestAt {
+    /// The block to dump the storage at.
+    /// Format: `<height|hash|final|near-final|optimistic>`, e.g. `112233`, `final`
+    #[clap(
+        verbatim_doc_comment,
+        value_name = "BLOCK",
+        value_parser = super::call::block_reference_from_str
+    )]
+    block: near_primitives::types::BlockReference,
+
+    #[clap(subcommand)]
+    rest: Option<StateCommandRestAtRest>,
+}
+
+impl StateCommandRestAt {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.block = Some(self.block);
+        match self.rest {
+            Some(StateCommandRestAtRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum StateCommandRestAtRest {
+    Through(StateCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx state \x1b[3mCONTRACT\x1b[0m [prefix \x1b[3mPREFIX\x1b[0m] [at \x1b[3mBLOCK\x1b[0m] \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m] [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct StateCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<StateCommandThroughRest>,
+}
+
+impl StateCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(StateCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum StateCommandThroughRest {
+    With(StateCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx state \x1b[3mCONTRACT\x1b[0m [prefix \x1b[3mPREFIX\x1b[0m] [at \x1b[3mBLOCK\x1b[0m] \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m [--json]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct StateCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl StateCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+fn prefix_from_str(s: &str) -> eyre::Result<near_primitives::types::StoreKey> {
+    let Some(hex) = s.strip_prefix("0x") else {
+        return Ok(s.as_bytes().to_vec().into());
+    };
+
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        eyre::bail!("`{}` is not valid hex", s);
+    }
+
+    if hex.len() % 2 != 0 {
+        eyre::bail!("`{}` has an odd number of hex digits", s);
+    }
+
+    Ok((0..hex.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).expect("the digits are hex"))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[derive(Default)]
+pub struct ConsumableCommand {
+    pub contract_id: Option<AccountId>,
+    pub prefix: Option<near_primitives::types::StoreKey>,
+    pub block: Option<near_primitives::types::BlockReference>,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+    pub json: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_prefixes() {
+        let bytes = |prefix| prefix_from_str(prefix).unwrap().to_vec();
+
+        assert_eq!(bytes("STATE"), b"STATE");
+        assert_eq!(bytes("0x00fF"), [0x00, 0xff]);
+        assert_eq!(bytes("0x"), b"");
+
+        for prefix in ["0x+f", "0x-1", "0x0g", "0x0", "0x ff"] {
+            assert!(prefix_from_str(prefix).is_err(), "{}", prefix);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use clap::{Parser, Subcommand};
+use const_format::concatcp;
+
+use near_account_id::AccountId;
+
+pub const EXAMPLES: &str = "
+  # Look up the outcome of a transaction sent by `\x1b[1mbob.testnet\x1b[0m`
+  $ nearx status \x1b[1;3m\"FvkVeTgRsECmN7JawSqZZz4acSvq5u74DJMAovsExaQE\"\x1b[0m from \x1b[1;3m\"bob.testnet\"\x1b[0m through \x1b[1;3m\"https://rpc.testnet.near.org\"\x1b[0m";
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx \x1b[1mstatus \x1b[3mTX_HASH\x1b[0m from \x1b[3mSENDER\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Looks up the outcome of a transaction
+pub struct StatusCommand {
+    /// The hash of the transaction.
+    #[clap(value_name = "TX_HASH")]
+    tx_hash: near_primitives::hash::CryptoHash,
+
+    #[clap(subcommand)]
+    rest: StatusCommandRest,
+}
+
+impl StatusCommand {
+    pub fn apply(self, command: &mut ConsumableCommand) {
+        command.tx_hash = Some(self.tx_hash);
+        let StatusCommandRest::From(from_sender) = self.rest;
+        from_sender.apply(command);
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum StatusCommandRest {
+    From(StatusCommandRestFrom),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx status \x1b[3mTX_HASH\x1b[0m \x1b[1mfrom \x1b[3mSENDER\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the account that signed the transaction.
+struct StatusCommandRestFrom {
+    /// The account that signed the transaction.
+    sender: AccountId,
+
+    #[clap(subcommand)]
+    rest: Option<StatusCommandRestFromRest>,
+}
+
+impl StatusCommandRestFrom {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.sender_id = Some(self.sender);
+        match self.rest {
+            Some(StatusCommandRestFromRest::Through(through_rpc)) => through_rpc.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum StatusCommandRestFromRest {
+    Through(StatusCommandThrough),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx status \x1b[3mTX_HASH\x1b[0m from \x1b[3mSENDER\x1b[0m \
+    \x1b[1mthrough \x1b[3mRPC_URL\x1b[0m [with \x1b[3mTOKEN\x1b[0m]",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC URL to connect to.
+struct StatusCommandThrough {
+    /// The RPC URL, or the name of a network, to connect to. [env: NEARX_NETWORK, NEAR_RPC_URL]
+    #[clap(value_name = "URL")]
+    rpc_url: String,
+
+    #[clap(subcommand)]
+    rest: Option<StatusCommandThroughRest>,
+}
+
+impl StatusCommandThrough {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_url = Some(self.rpc_url);
+        match self.rest {
+            Some(StatusCommandThroughRest::With(with_api_key)) => with_api_key.apply(command),
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum StatusCommandThroughRest {
+    With(StatusCommandThroughRestWith),
+}
+
+#[derive(Debug, Parser)]
+#[clap(after_help = concatcp!(
+    "\x1b[1;4mHint:\x1b[0m\n  \
+    nearx status \x1b[3mTX_HASH\x1b[0m from \x1b[3mSENDER\x1b[0m \
+    through \x1b[3mRPC_URL\x1b[0m \x1b[1mwith \x1b[3mTOKEN\x1b[0m",
+    super::EXAMPLES_HEADER,
+    EXAMPLES
+))]
+/// Defines the RPC API key to use.
+struct StatusCommandThroughRestWith {
+    /// The RPC API key to use, sent as the `x-api-key` header.
+    /// [env: NEAR_RPC_API_KEY]
+    #[clap(value_name = "KEY", value_parser = super::call::auth_header_from_str)]
+    rpc_api_key: super::AuthHeader,
+}
+
+impl StatusCommandThroughRestWith {
+    fn apply(self, command: &mut ConsumableCommand) {
+        command.rpc_api_key = Some(self.rpc_api_key);
+    }
+}
+
+#[derive(Default)]
+pub struct ConsumableCommand {
+    pub tx_hash: Option<near_primitives::hash::CryptoHash>,
+    pub sender_id: Option<AccountId>,
+    pub rpc_url: Option<String>,
+    pub rpc_api_key: Option<super::AuthHeader>,
+}
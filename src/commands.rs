@@ -1,5 +1,45 @@
+mod account;
+mod add;
+mod batch;
+mod broadcast;
 mod call;
+mod create;
+mod delete;
+mod deploy;
 mod dissect;
+mod keygen;
+mod keys;
+mod keystore;
+mod receipts;
+mod rpc;
+mod send;
+mod state;
+mod status;
+mod transaction;
 
+use crate::cli;
+
+pub use account::run as account;
+pub use add::run_key as add_key;
+pub use batch::run as batch;
+pub use broadcast::run as broadcast;
 pub use call::run as call;
+pub use create::run_account as create_account;
+pub use delete::run_account as delete_account;
+pub use delete::run_key as delete_key;
+pub use deploy::run as deploy;
 pub use dissect::run as dissect;
+pub use keygen::run as keygen;
+pub use keys::run_list as keys;
+pub use keys::run_view as key;
+pub use keystore::run_export as keystore_export;
+pub use keystore::run_import as keystore_import;
+pub use keystore::run_list as keystore_list;
+pub use keystore::run_remove as keystore_remove;
+pub use send::run as send;
+pub use state::run as state;
+pub use status::run as status;
+
+fn connect(rpc: &cli::Rpc) -> color_eyre::eyre::Result<rpc::Client> {
+    rpc::Client::connect(rpc)
+}
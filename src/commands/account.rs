@@ -0,0 +1,91 @@
+use color_eyre::eyre;
+use serde::Deserialize;
+use serde_json::json;
+
+use near_jsonrpc_client::methods;
+use near_jsonrpc_primitives::types::config::RpcProtocolConfigError;
+use near_jsonrpc_primitives::types::query::QueryResponseKind;
+use near_primitives::hash::CryptoHash;
+use near_primitives::types::Balance;
+use near_primitives::views::QueryRequest;
+
+use crate::exit::{ExitCode, WithExitCode};
+use crate::macros::info;
+use crate::{cli, utils};
+
+pub async fn run(command: cli::AccountCommand) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let request = methods::query::RpcQueryRequest {
+        block_reference: command.block.clone(),
+        request: QueryRequest::ViewAccount {
+            account_id: command.account_id.clone(),
+        },
+    };
+
+    let response = client.call(request).await.exit_code(ExitCode::Rpc)?;
+
+    let account = match response.kind {
+        QueryResponseKind::ViewAccount(account) => account,
+        err => unreachable!("unexpected response kind: {:?}", err),
+    };
+
+    let request = methods::any::<Result<ProtocolConfig, RpcProtocolConfigError>>(
+        "EXPERIMENTAL_protocol_config",
+        serde_json::to_value(&command.block).expect("block references are serializable"),
+    );
+
+    let config = client.call(request).await.exit_code(ExitCode::Rpc)?;
+
+    let storage_cost = Balance::from(account.storage_usage)
+        .saturating_mul(config.runtime_config.storage_amount_per_byte);
+
+    if command.json {
+        let document = json!({
+            "account_id": command.account_id,
+            "block_hash": response.block_hash,
+            "block_height": response.block_height,
+            "balance": account.amount.to_string(),
+            "locked": account.locked.to_string(),
+            "storage_usage": account.storage_usage,
+            "storage_cost": storage_cost.to_string(),
+            "code_hash": account.code_hash,
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&document).expect("json is valid")
+        );
+        return Ok(());
+    }
+
+    info!("    block height: \x1b[1m{}\x1b[0m", response.block_height);
+    info!("      block hash: \x1b[1m{}\x1b[0m", response.block_hash);
+
+    println!("      account: {}", command.account_id);
+    println!("      balance: {}", utils::format_balance(account.amount));
+    println!("       locked: {}", utils::format_balance(account.locked));
+    println!("storage usage: {} bytes", account.storage_usage);
+    println!(" storage cost: {}", utils::format_balance(storage_cost));
+    if account.code_hash == CryptoHash::default() {
+        println!("    code hash: {} (no contract)", account.code_hash);
+    } else {
+        println!("    code hash: {}", account.code_hash);
+    }
+
+    Ok(())
+}
+
+/// The parts of the protocol config we need, so that configs from newer nodes still parse.
+#[derive(Deserialize)]
+struct ProtocolConfig {
+    runtime_config: RuntimeConfig,
+}
+
+#[derive(Deserialize)]
+struct RuntimeConfig {
+    /// The cost of storing a single byte on chain, in yoctoNEAR.
+    #[serde(with = "near_primitives::serialize::dec_format")]
+    storage_amount_per_byte: Balance,
+}
+
+impl methods::RpcHandlerResponse for ProtocolConfig {}
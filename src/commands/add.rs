@@ -0,0 +1,38 @@
+use color_eyre::eyre;
+
+use crate::cli;
+
+use super::transaction::transact;
+
+pub async fn run_key(command: cli::AddKeyCommand, options: &cli::Options) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let signer =
+        near_crypto::InMemorySigner::from_secret_key(command.account_id, command.secret_key);
+
+    let access_key = near_primitives::views::AccessKeyView {
+        nonce: 0,
+        permission: command.permission,
+    };
+
+    let actions = vec![near_primitives::transaction::Action::AddKey(
+        near_primitives::transaction::AddKeyAction {
+            public_key: command.public_key,
+            access_key: access_key.into(),
+        },
+    )];
+
+    let receiver_id = signer.account_id.clone();
+
+    transact(
+        client,
+        signer,
+        receiver_id,
+        actions,
+        command.action,
+        options,
+    )
+    .await?;
+
+    Ok(())
+}
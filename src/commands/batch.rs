@@ -0,0 +1,130 @@
+use color_eyre::eyre;
+use tokio::io::AsyncReadExt;
+
+use near_primitives::transaction::Action;
+
+use crate::cli::{self, BatchAction};
+use crate::exit::{ExitCode, WithExitCode};
+use crate::utils;
+
+use super::deploy::read_code;
+use super::transaction::transact;
+
+const MAX_PREPAID_GAS: near_primitives::types::Gas = utils::TGAS * 300;
+
+pub async fn run(command: cli::BatchCommand, options: &cli::Options) -> eyre::Result<()> {
+    let batch = match command.actions {
+        Some(actions) => actions,
+        None => {
+            let mut actions = String::new();
+            tokio::io::stdin().read_to_string(&mut actions).await?;
+            BatchAction::parse_list(&actions).exit_code(ExitCode::InvalidInput)?
+        }
+    };
+
+    if batch.is_empty() {
+        return Err(eyre::eyre!("a transaction needs at least one action"))
+            .exit_code(ExitCode::InvalidInput);
+    }
+
+    let (explicit_gas, implicit_gas_calls) =
+        batch
+            .iter()
+            .fold((0u64, 0u64), |(explicit, implicit), action| match action {
+                BatchAction::Call { gas: Some(gas), .. } => {
+                    (explicit.saturating_add(*gas), implicit)
+                }
+                BatchAction::Call { gas: None, .. } => (explicit, implicit + 1),
+                _ => (explicit, implicit),
+            });
+
+    let default_gas = match (command.gas, implicit_gas_calls) {
+        (Some(gas), _) => gas,
+        (None, 0) => 0,
+        (None, calls) => MAX_PREPAID_GAS.saturating_sub(explicit_gas) / calls,
+    };
+
+    let mut actions = Vec::with_capacity(batch.len());
+    for action in batch {
+        actions.push(match action {
+            BatchAction::CreateAccount => {
+                Action::CreateAccount(near_primitives::transaction::CreateAccountAction {})
+            }
+            BatchAction::Deploy { wasm } => {
+                Action::DeployContract(near_primitives::transaction::DeployContractAction {
+                    code: read_code(Some(wasm)).await?,
+                })
+            }
+            BatchAction::Call {
+                method,
+                args,
+                gas,
+                deposit,
+            } => Action::FunctionCall(near_primitives::transaction::FunctionCallAction {
+                method_name: method,
+                args: serde_json::to_vec(&args)?,
+                gas: gas.unwrap_or(default_gas),
+                deposit: deposit.unwrap_or(0),
+            }),
+            BatchAction::Transfer { amount } => {
+                Action::Transfer(near_primitives::transaction::TransferAction { deposit: amount })
+            }
+            BatchAction::AddKey {
+                public_key,
+                receiver_id,
+                method_names,
+                allowance,
+            } => {
+                let permission = match receiver_id {
+                    Some(receiver_id) => {
+                        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                            allowance,
+                            receiver_id: receiver_id.into(),
+                            method_names,
+                        }
+                    }
+                    None => near_primitives::views::AccessKeyPermissionView::FullAccess,
+                };
+
+                let access_key = near_primitives::views::AccessKeyView {
+                    nonce: 0,
+                    permission,
+                };
+
+                Action::AddKey(near_primitives::transaction::AddKeyAction {
+                    public_key,
+                    access_key: access_key.into(),
+                })
+            }
+            BatchAction::DeleteKey { public_key } => {
+                Action::DeleteKey(near_primitives::transaction::DeleteKeyAction { public_key })
+            }
+            BatchAction::DeleteAccount { beneficiary_id } => {
+                Action::DeleteAccount(near_primitives::transaction::DeleteAccountAction {
+                    beneficiary_id,
+                })
+            }
+        });
+    }
+
+    let client = super::connect(&command.rpc)?;
+
+    let signer =
+        near_crypto::InMemorySigner::from_secret_key(command.signer_id, command.secret_key);
+
+    let receiver_id = command
+        .receiver
+        .unwrap_or_else(|| signer.account_id.clone());
+
+    transact(
+        client,
+        signer,
+        receiver_id,
+        actions,
+        command.action,
+        options,
+    )
+    .await?;
+
+    Ok(())
+}
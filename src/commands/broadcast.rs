@@ -0,0 +1,39 @@
+use color_eyre::eyre;
+
+use crate::cli;
+use crate::macros::{info, warn};
+
+use super::dissect::read_signed_tx;
+use super::transaction::broadcast;
+
+pub async fn run(command: cli::BroadcastCommand, options: &cli::Options) -> eyre::Result<()> {
+    let signed_transaction = match command.signed_tx {
+        Some(signed_tx) => signed_tx,
+        None => read_signed_tx().await?,
+    };
+
+    let client = super::connect(&command.rpc)?;
+
+    info!(
+        "transaction hash: \x1b[1m{}\x1b[0m",
+        signed_transaction.get_hash()
+    );
+
+    let options = match command.mode {
+        cli::BroadcastMode::Commit => options.clone(),
+        cli::BroadcastMode::Async => {
+            if options.receipts.is_some() {
+                warn!("`async` broadcasts don't wait for the transaction, ignoring `--receipts`");
+            }
+
+            cli::Options {
+                wait: cli::WaitUntil::None,
+                ..options.clone()
+            }
+        }
+    };
+
+    broadcast(&client, signed_transaction, &options).await?;
+
+    Ok(())
+}
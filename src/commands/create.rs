@@ -0,0 +1,78 @@
+use color_eyre::eyre;
+
+use near_primitives::transaction::Action;
+
+use crate::cli;
+use crate::exit::{ExitCode, WithExitCode};
+use crate::macros::info;
+
+use super::transaction::transact;
+
+pub async fn run_account(
+    command: cli::CreateAccountCommand,
+    options: &cli::Options,
+) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let signer =
+        near_crypto::InMemorySigner::from_secret_key(command.signer_id, command.secret_key);
+
+    let transfer = Action::Transfer(near_primitives::transaction::TransferAction {
+        deposit: command.deposit,
+    });
+
+    let actions = if command.new_account_id.is_implicit() {
+        if command.public_key.is_some() {
+            return Err(eyre::eyre!(
+                "implicit accounts are controlled by the key they're derived from, \
+                 drop `\x1b[1mwith \x1b[3m<PUBLIC_KEY>\x1b[0m`"
+            ))
+            .exit_code(ExitCode::InvalidInput);
+        }
+
+        if command.deposit == 0 {
+            return Err(eyre::eyre!(
+                "implicit accounts are created by funding them, specify a deposit"
+            ))
+            .exit_code(ExitCode::InvalidInput);
+        }
+
+        vec![transfer]
+    } else {
+        if !command.new_account_id.is_sub_account_of(&signer.account_id) {
+            return Err(eyre::eyre!(
+                "`{}` can only create sub-accounts of itself, \
+                 top-level accounts have to be created through the registrar",
+                signer.account_id
+            ))
+            .exit_code(ExitCode::InvalidInput);
+        }
+
+        let public_key = command
+            .public_key
+            .unwrap_or_else(|| signer.public_key.clone());
+
+        info!("      public key: \x1b[1m{}\x1b[0m", public_key);
+
+        vec![
+            Action::CreateAccount(near_primitives::transaction::CreateAccountAction {}),
+            transfer,
+            Action::AddKey(near_primitives::transaction::AddKeyAction {
+                public_key,
+                access_key: near_primitives::account::AccessKey::full_access(),
+            }),
+        ]
+    };
+
+    transact(
+        client,
+        signer,
+        command.new_account_id,
+        actions,
+        command.action,
+        options,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use color_eyre::eyre;
+
+use crate::cli;
+use crate::macros::warn;
+
+use super::transaction::transact;
+
+pub async fn run_account(
+    command: cli::DeleteAccountCommand,
+    options: &cli::Options,
+) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let signer =
+        near_crypto::InMemorySigner::from_secret_key(command.account_id, command.secret_key);
+
+    let actions = vec![near_primitives::transaction::Action::DeleteAccount(
+        near_primitives::transaction::DeleteAccountAction {
+            beneficiary_id: command.beneficiary_id,
+        },
+    )];
+
+    let receiver_id = signer.account_id.clone();
+
+    transact(
+        client,
+        signer,
+        receiver_id,
+        actions,
+        command.action,
+        options,
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn run_key(command: cli::DeleteKeyCommand, options: &cli::Options) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let signer =
+        near_crypto::InMemorySigner::from_secret_key(command.account_id, command.secret_key);
+
+    if command.public_key == signer.public_key {
+        warn!("deleting the access key used to sign this transaction");
+    }
+
+    let actions = vec![near_primitives::transaction::Action::DeleteKey(
+        near_primitives::transaction::DeleteKeyAction {
+            public_key: command.public_key,
+        },
+    )];
+
+    let receiver_id = signer.account_id.clone();
+
+    transact(
+        client,
+        signer,
+        receiver_id,
+        actions,
+        command.action,
+        options,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use color_eyre::eyre;
+use tokio::io::AsyncReadExt;
+
+use near_primitives::transaction::Action;
+
+use crate::cli;
+use crate::exit::{ExitCode, WithExitCode};
+use crate::macros::{info, warn};
+
+use super::transaction::transact;
+
+pub async fn run(command: cli::DeployCommand, options: &cli::Options) -> eyre::Result<()> {
+    let code = read_code(command.wasm).await?;
+
+    let client = super::connect(&command.rpc)?;
+
+    let signer =
+        near_crypto::InMemorySigner::from_secret_key(command.signer_id, command.secret_key);
+
+    let mut actions = vec![Action::DeployContract(
+        near_primitives::transaction::DeployContractAction { code },
+    )];
+
+    if let Some(init) = command.init {
+        actions.push(Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: init.method,
+                args: serde_json::to_vec(&init.args)?,
+                gas: init.gas,
+                deposit: init.deposit,
+            },
+        ));
+    }
+
+    let receiver_id = signer.account_id.clone();
+
+    transact(
+        client,
+        signer,
+        receiver_id,
+        actions,
+        command.action,
+        options,
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub(super) async fn read_code(path: Option<PathBuf>) -> eyre::Result<Vec<u8>> {
+    let code = match path {
+        Some(path) => tokio::fs::read(&path)
+            .await
+            .map_err(|err| eyre::eyre!("failed to read `{}`: {}", path.display(), err))
+            .exit_code(ExitCode::InvalidInput)?,
+        None => {
+            let mut code = vec![];
+            tokio::io::stdin().read_to_end(&mut code).await?;
+            code
+        }
+    };
+
+    if !code.starts_with(b"\0asm") {
+        warn!("the contract code does not look like a WebAssembly module");
+    }
+
+    info!(
+        "       code hash: \x1b[1m{}\x1b[0m",
+        near_primitives::hash::CryptoHash::hash_bytes(&code)
+    );
+
+    Ok(code)
+}
@@ -7,6 +7,7 @@ use tokio_stream::StreamExt;
 use near_primitives::transaction::SignedTransaction;
 
 use crate::cli;
+use crate::exit::{ExitCode, WithExitCode};
 use crate::macros::error;
 
 pub async fn run(command: cli::DissectCommand) -> eyre::Result<()> {
@@ -51,5 +52,5 @@ pub async fn read_signed_tx() -> eyre::Result<SignedTransaction> {
         return Ok(signed_tx);
     }
 
-    Err(eyre::eyre!("failed to read signed transaction"))
+    Err(eyre::eyre!("failed to read signed transaction")).exit_code(ExitCode::InvalidInput)
 }
@@ -0,0 +1,83 @@
+use color_eyre::eyre;
+
+use near_account_id::AccountId;
+use near_crypto::{KeyType, SecretKey};
+
+use crate::cli;
+use crate::credentials;
+use crate::exit::{ExitCode, WithExitCode};
+use crate::macros::info;
+
+pub async fn run(command: cli::KeygenCommand) -> eyre::Result<()> {
+    let secret_key = SecretKey::from_random(command.key_type);
+    let public_key = secret_key.public_key();
+
+    // only ed25519 keys have implicit accounts
+    let implicit_account_id = match command.key_type {
+        KeyType::ED25519 => Some(
+            public_key
+                .key_data()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>()
+                .parse::<AccountId>()?,
+        ),
+        KeyType::SECP256K1 => None,
+    };
+
+    info!("      public key: \x1b[1m{}\x1b[0m", public_key);
+    if let Some(implicit_account_id) = &implicit_account_id {
+        info!("implicit account: \x1b[1m{}\x1b[0m", implicit_account_id);
+    }
+
+    let account_id = command.account_id.or(implicit_account_id);
+
+    // resolve where to save the key before printing it, so nothing is printed for a failed save
+    let path = match (command.save, &account_id) {
+        (None, _) => None,
+        (Some(Some(path)), _) => Some(path),
+        (Some(None), Some(account_id)) => credentials::network(account_id, "")
+            .and_then(|network| credentials::credentials_path(account_id, &network))
+            .ok_or_else(|| {
+                eyre::eyre!(
+                    "couldn't tell which network `{}` is on, set `NEAR_ENV` or specify a path",
+                    account_id
+                )
+            })
+            .map(Some)
+            .exit_code(ExitCode::InvalidInput)?,
+        (Some(None), None) => {
+            return Err(eyre::eyre!(
+                "secp256k1 keys have no implicit account, specify one with `for <ACCOUNT>`"
+            ))
+            .exit_code(ExitCode::InvalidInput);
+        }
+    };
+
+    if let Some(path) = path.as_ref().filter(|path| path.exists()) {
+        return Err(eyre::eyre!(
+            "`{}` already exists, refusing to overwrite it",
+            path.display()
+        ))
+        .exit_code(ExitCode::InvalidInput);
+    }
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&credentials::key_file_json(
+            account_id.as_ref(),
+            &secret_key
+        ))?
+    );
+
+    let Some(path) = path else {
+        return Ok(());
+    };
+
+    credentials::write_key_file(&path, account_id.as_ref(), &secret_key)
+        .exit_code(ExitCode::InvalidInput)?;
+
+    info!("        saved to: \x1b[1m{}\x1b[0m", path.display());
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use color_eyre::eyre;
+use serde_json::json;
+
+use near_jsonrpc_client::methods;
+use near_jsonrpc_primitives::types::query::QueryResponseKind;
+use near_primitives::views::{AccessKeyPermissionView, AccessKeyView, QueryRequest};
+
+use crate::exit::{ExitCode, WithExitCode};
+use crate::macros::info;
+use crate::{cli, utils};
+
+pub async fn run_list(command: cli::KeysCommand) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let request = methods::query::RpcQueryRequest {
+        block_reference: command.block,
+        request: QueryRequest::ViewAccessKeyList {
+            account_id: command.account_id.clone(),
+        },
+    };
+
+    let response = client.call(request).await.exit_code(ExitCode::Rpc)?;
+
+    let list = match response.kind {
+        QueryResponseKind::AccessKeyList(list) => list,
+        err => unreachable!("unexpected response kind: {:?}", err),
+    };
+
+    if command.json {
+        let document = json!({
+            "account_id": command.account_id,
+            "block_hash": response.block_hash,
+            "block_height": response.block_height,
+            "keys": list
+                .keys
+                .iter()
+                .map(|key| key_to_json(&key.public_key, &key.access_key))
+                .collect::<Vec<_>>(),
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&document).expect("json is valid")
+        );
+        return Ok(());
+    }
+
+    info!("    block height: \x1b[1m{}\x1b[0m", response.block_height);
+    info!("      block hash: \x1b[1m{}\x1b[0m", response.block_hash);
+
+    if list.keys.is_empty() {
+        info!("{} has no access keys", command.account_id);
+        return Ok(());
+    }
+
+    for key in &list.keys {
+        println!(
+            "{}\t{}\t{}",
+            key.public_key,
+            key.access_key.nonce,
+            permission(&key.access_key.permission)
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn run_view(command: cli::KeyCommand) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let request = methods::query::RpcQueryRequest {
+        block_reference: command.block,
+        request: QueryRequest::ViewAccessKey {
+            account_id: command.account_id.clone(),
+            public_key: command.public_key.clone(),
+        },
+    };
+
+    let response = client.call(request).await.exit_code(ExitCode::Rpc)?;
+
+    let access_key = match response.kind {
+        QueryResponseKind::AccessKey(access_key) => access_key,
+        err => unreachable!("unexpected response kind: {:?}", err),
+    };
+
+    if command.json {
+        let mut document = key_to_json(&command.public_key, &access_key);
+        document["account_id"] = json!(command.account_id);
+        document["block_hash"] = json!(response.block_hash);
+        document["block_height"] = json!(response.block_height);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&document).expect("json is valid")
+        );
+        return Ok(());
+    }
+
+    info!("    block height: \x1b[1m{}\x1b[0m", response.block_height);
+    info!("      block hash: \x1b[1m{}\x1b[0m", response.block_hash);
+
+    println!("   account: {}", command.account_id);
+    println!("public key: {}", command.public_key);
+    println!("     nonce: {}", access_key.nonce);
+    match access_key.permission {
+        AccessKeyPermissionView::FullAccess => println!("permission: full access"),
+        AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id,
+            method_names,
+        } => {
+            println!("permission: function call");
+            println!("  receiver: {}", receiver_id);
+            if method_names.is_empty() {
+                println!("   methods: any");
+            } else {
+                println!("   methods: {}", method_names.join(", "));
+            }
+            println!(" allowance: {}", allowance_to_string(allowance));
+        }
+    }
+
+    Ok(())
+}
+
+/// Describes a permission on a single line, e.g. `full access`.
+fn permission(permission: &AccessKeyPermissionView) -> String {
+    match permission {
+        AccessKeyPermissionView::FullAccess => "full access".to_owned(),
+        AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id,
+            method_names,
+        } => format!(
+            "function call to {} ({}), allowance: {}",
+            receiver_id,
+            if method_names.is_empty() {
+                "any method".to_owned()
+            } else {
+                method_names.join(", ")
+            },
+            allowance_to_string(*allowance)
+        ),
+    }
+}
+
+fn allowance_to_string(allowance: Option<near_primitives::types::Balance>) -> String {
+    match allowance {
+        Some(allowance) => utils::format_balance(allowance),
+        None => "unlimited".to_owned(),
+    }
+}
+
+fn key_to_json(
+    public_key: &near_crypto::PublicKey,
+    access_key: &AccessKeyView,
+) -> serde_json::Value {
+    let permission = match &access_key.permission {
+        AccessKeyPermissionView::FullAccess => json!("full_access"),
+        AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id,
+            method_names,
+        } => json!({
+            "receiver_id": receiver_id,
+            "method_names": method_names,
+            "allowance": allowance.map(|allowance| allowance.to_string()),
+        }),
+    };
+
+    json!({
+        "public_key": public_key,
+        "nonce": access_key.nonce,
+        "permission": permission,
+    })
+}
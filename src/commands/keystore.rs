@@ -0,0 +1,82 @@
+use color_eyre::eyre::{self, WrapErr};
+
+use crate::cli;
+use crate::exit::{ExitCode, WithExitCode};
+use crate::keystore::{self, EncryptedKey};
+use crate::macros::{info, warn};
+
+pub async fn run_import(command: cli::KeystoreImportCommand) -> eyre::Result<()> {
+    let secret_key = match command.secret_key {
+        Some(secret_key) => secret_key,
+        None => {
+            let secret_key = rpassword::prompt_password(format!(
+                "secret key or seed phrase for {}: ",
+                command.account_id
+            ))
+            .wrap_err("failed to read the secret key")?;
+
+            crate::credentials::secret_key_from_str(&secret_key)
+                .exit_code(ExitCode::InvalidInput)?
+        }
+    };
+
+    if keystore::read(&command.network, &command.account_id)?.is_some() {
+        warn!(
+            "replacing the key of `\x1b[1m{}\x1b[0m` on {}",
+            command.account_id, command.network
+        );
+    }
+
+    let passphrase = keystore::new_passphrase()?;
+    let key = EncryptedKey::encrypt(command.account_id, &secret_key, &passphrase)?;
+    let path = keystore::write(&command.network, &key)?;
+
+    info!("public key: \x1b[1m{}\x1b[0m", key.public_key);
+    info!("  saved to: \x1b[1m{}\x1b[0m", path.display());
+
+    Ok(())
+}
+
+pub async fn run_list(command: cli::KeystoreListCommand) -> eyre::Result<()> {
+    let keys = keystore::list(command.network.as_deref())?;
+
+    if keys.is_empty() {
+        info!("the keystore is empty");
+    }
+
+    for (network, key) in keys {
+        println!("{}\t{}\t{}", network, key.account_id, key.public_key);
+    }
+
+    Ok(())
+}
+
+pub async fn run_export(command: cli::KeystoreExportCommand) -> eyre::Result<()> {
+    let key = read(&command.account_id, &command.network)?;
+
+    let passphrase = keystore::passphrase(&format!("passphrase for {}: ", command.account_id))?;
+    let secret_key = key.decrypt(&passphrase).exit_code(ExitCode::InvalidInput)?;
+
+    println!("{}", secret_key);
+
+    Ok(())
+}
+
+pub async fn run_remove(command: cli::KeystoreRemoveCommand) -> eyre::Result<()> {
+    let key = read(&command.account_id, &command.network)?;
+
+    std::fs::remove_file(keystore::path(&command.network, &command.account_id)?)?;
+
+    info!(
+        "removed \x1b[1m{}\x1b[0m of `\x1b[1m{}\x1b[0m` on {}",
+        key.public_key, command.account_id, command.network
+    );
+
+    Ok(())
+}
+
+fn read(account_id: &near_account_id::AccountId, network: &str) -> eyre::Result<EncryptedKey> {
+    keystore::read(network, account_id)?
+        .ok_or_else(|| eyre::eyre!("no key for `{}` on {} in the keystore", account_id, network))
+        .exit_code(ExitCode::InvalidInput)
+}
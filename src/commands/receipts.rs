@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use near_primitives::hash::CryptoHash;
+use near_primitives::views::{
+    ActionView, ExecutionOutcomeWithIdView, ExecutionStatusView,
+    FinalExecutionOutcomeWithReceiptView, ReceiptEnumView, ReceiptView,
+};
+use serde_json::json;
+
+use crate::macros::info;
+use crate::utils;
+
+/// Indexes the outcomes and receipts of a transaction by their ids.
+struct Tree<'a> {
+    outcomes: HashMap<CryptoHash, &'a ExecutionOutcomeWithIdView>,
+    receipts: HashMap<CryptoHash, &'a ReceiptView>,
+}
+
+impl<'a> Tree<'a> {
+    fn new(response: &'a FinalExecutionOutcomeWithReceiptView) -> Self {
+        Self {
+            outcomes: response
+                .final_outcome
+                .receipts_outcome
+                .iter()
+                .map(|outcome| (outcome.id, outcome))
+                .collect(),
+            receipts: response
+                .receipts
+                .iter()
+                .map(|receipt| (receipt.receipt_id, receipt))
+                .collect(),
+        }
+    }
+
+    fn children(
+        &self,
+        outcome: &ExecutionOutcomeWithIdView,
+    ) -> Vec<&'a ExecutionOutcomeWithIdView> {
+        outcome
+            .outcome
+            .receipt_ids
+            .iter()
+            .filter_map(|id| self.outcomes.get(id).copied())
+            .collect()
+    }
+}
+
+/// Draws the receipt tree of a transaction on stderr.
+pub fn render(response: &FinalExecutionOutcomeWithReceiptView) {
+    let tree = Tree::new(response);
+    let transaction = &response.final_outcome.transaction;
+    let outcome = &response.final_outcome.transaction_outcome;
+
+    info!("receipts:");
+    info!(
+        "\x1b[1m{}\x1b[0m → \x1b[1m{}\x1b[0m  {}  {}  \x1b[2m{}\x1b[0m",
+        transaction.signer_id,
+        transaction.receiver_id,
+        gas(outcome),
+        status(&outcome.outcome.status),
+        outcome.id
+    );
+
+    render_children(&tree, outcome, "");
+}
+
+fn render_children(tree: &Tree, outcome: &ExecutionOutcomeWithIdView, prefix: &str) {
+    let logs = &outcome.outcome.logs;
+    let children = tree.children(outcome);
+    let count = logs.len() + children.len();
+
+    for (idx, log) in logs.iter().enumerate() {
+        let branch = if idx + 1 == count { "└─" } else { "├─" };
+        info!("{}{} \x1b[38;5;71mlog\x1b[0m {}", prefix, branch, log);
+    }
+
+    for (idx, child) in children.into_iter().enumerate() {
+        let last = logs.len() + idx + 1 == count;
+        let (branch, indent) = if last {
+            ("└─", "   ")
+        } else {
+            ("├─", "│  ")
+        };
+
+        let actions = actions(tree.receipts.get(&child.id).copied());
+
+        info!(
+            "{}{} \x1b[1m{}\x1b[0m: {}  {}  {}  \x1b[2m{}\x1b[0m",
+            prefix,
+            branch,
+            child.outcome.executor_id,
+            if actions.is_empty() {
+                "?".to_owned()
+            } else {
+                actions.join(", ")
+            },
+            gas(child),
+            status(&child.outcome.status),
+            child.id
+        );
+
+        render_children(tree, child, &format!("{}{}", prefix, indent));
+    }
+}
+
+/// Describes the receipt tree of a transaction as JSON.
+pub fn to_json(response: &FinalExecutionOutcomeWithReceiptView) -> serde_json::Value {
+    let tree = Tree::new(response);
+    let transaction = &response.final_outcome.transaction;
+    let outcome = &response.final_outcome.transaction_outcome;
+
+    json!({
+        "hash": outcome.id,
+        "signer_id": transaction.signer_id,
+        "receiver_id": transaction.receiver_id,
+        "status": response.final_outcome.status,
+        "gas_burnt": outcome.outcome.gas_burnt,
+        "tokens_burnt": outcome.outcome.tokens_burnt.to_string(),
+        "logs": outcome.outcome.logs,
+        "receipts": tree
+            .children(outcome)
+            .into_iter()
+            .map(|child| receipt_to_json(&tree, child))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn receipt_to_json(tree: &Tree, outcome: &ExecutionOutcomeWithIdView) -> serde_json::Value {
+    let receipt = tree.receipts.get(&outcome.id).copied();
+
+    json!({
+        "id": outcome.id,
+        "predecessor_id": receipt.map(|receipt| &receipt.predecessor_id),
+        "executor_id": outcome.outcome.executor_id,
+        "actions": actions(receipt),
+        "status": outcome.outcome.status,
+        "gas_burnt": outcome.outcome.gas_burnt,
+        "tokens_burnt": outcome.outcome.tokens_burnt.to_string(),
+        "logs": outcome.outcome.logs,
+        "receipts": tree
+            .children(outcome)
+            .into_iter()
+            .map(|child| receipt_to_json(tree, child))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn actions(receipt: Option<&ReceiptView>) -> Vec<String> {
+    let Some(receipt) = receipt else {
+        return vec![];
+    };
+
+    let actions = match &receipt.receipt {
+        ReceiptEnumView::Action { actions, .. } => actions,
+        ReceiptEnumView::Data { .. } => return vec!["data".to_owned()],
+    };
+
+    actions
+        .iter()
+        .map(|action| match action {
+            ActionView::CreateAccount => "create_account".to_owned(),
+            ActionView::DeployContract { .. } => "deploy".to_owned(),
+            ActionView::FunctionCall { method_name, .. } => format!("{}()", method_name),
+            ActionView::Transfer { .. } => "transfer".to_owned(),
+            ActionView::Stake { .. } => "stake".to_owned(),
+            ActionView::AddKey { .. } => "add_key".to_owned(),
+            ActionView::DeleteKey { .. } => "delete_key".to_owned(),
+            ActionView::DeleteAccount { .. } => "delete_account".to_owned(),
+            ActionView::Delegate { .. } => "delegate".to_owned(),
+        })
+        .collect()
+}
+
+fn gas(outcome: &ExecutionOutcomeWithIdView) -> String {
+    format!(
+        "{:.4} TGas",
+        outcome.outcome.gas_burnt as f64 / utils::TGAS as f64
+    )
+}
+
+fn status(status: &ExecutionStatusView) -> String {
+    match status {
+        ExecutionStatusView::Unknown => "\x1b[33mpending\x1b[0m".to_owned(),
+        ExecutionStatusView::Failure(error) => format!("\x1b[31mfailed\x1b[0m ({})", error),
+        ExecutionStatusView::SuccessValue(_) | ExecutionStatusView::SuccessReceiptId(_) => {
+            "\x1b[32msuccess\x1b[0m".to_owned()
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::time::Duration;
+
+use color_eyre::eyre;
+use near_jsonrpc_client::errors::{
+    JsonRpcError, JsonRpcServerError, JsonRpcServerResponseStatusError, JsonRpcTransportRecvError,
+    JsonRpcTransportSendError, RpcTransportError,
+};
+use near_jsonrpc_client::{methods, JsonRpcClient, MethodCallResult};
+use near_primitives::hash::CryptoHash;
+use near_primitives::transaction::SignedTransaction;
+use near_primitives::types::AccountId;
+use near_primitives::views::FinalExecutionOutcomeView;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::cli;
+use crate::macros::{info, warn};
+
+/// The delay before the first retry, doubled on every retry after that.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// How long to wait between polls of a transaction that's still pending.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long every attempt may wait on a transaction when there's no `--timeout`.
+const DEFAULT_TX_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Spreads requests over the RPC and its fallbacks, retrying transient failures.
+pub struct Client {
+    clients: Vec<JsonRpcClient>,
+    retries: u32,
+    /// How long to wait on a transaction in total, across all attempts.
+    tx_timeout: Duration,
+}
+
+impl Client {
+    pub fn connect(rpc: &cli::Rpc) -> eyre::Result<Self> {
+        let connector = match rpc.timeout {
+            None => JsonRpcClient::new_client(),
+            Some(timeout) => {
+                let mut headers = reqwest::header::HeaderMap::new();
+                headers.insert(
+                    reqwest::header::CONTENT_TYPE,
+                    reqwest::header::HeaderValue::from_static("application/json"),
+                );
+
+                JsonRpcClient::with(
+                    reqwest::Client::builder()
+                        .default_headers(headers)
+                        .timeout(timeout)
+                        .build()?,
+                )
+            }
+        };
+
+        let clients = std::iter::once(&rpc.url)
+            .chain(&rpc.fallback_urls)
+            .map(|url| {
+                let mut client = connector.connect(url);
+
+                for header in &rpc.headers {
+                    client
+                        .headers_mut()
+                        .insert(header.name.clone(), header.value.clone());
+                }
+
+                client
+            })
+            .collect();
+
+        Ok(Self {
+            clients,
+            retries: rpc.retries,
+            tx_timeout: rpc
+                .timeout
+                .unwrap_or(DEFAULT_TX_TIMEOUT)
+                .saturating_mul(rpc.retries.saturating_add(1)),
+        })
+    }
+
+    /// Calls a method, moving on to the next RPC after every transient failure.
+    pub async fn call<M>(&self, method: M) -> MethodCallResult<M::Response, M::Error>
+    where
+        M: methods::RpcMethod,
+        M::Error: Transient,
+    {
+        let mut attempt = 0;
+
+        loop {
+            match self.client(attempt).call(&method).await {
+                Err(err) if attempt < self.retries && is_transient(&err) => {
+                    self.backoff(attempt, attempt, &err).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Submits a transaction and waits until it has been included, executed or finalized,
+    /// returning its outcome unless only inclusion was awaited.
+    ///
+    /// A failed attempt may still have reached the network, so retries look the transaction up
+    /// by its hash, and only submit it again if no RPC knows about it. Either way, the same
+    /// signed transaction is used, so it can't be executed twice. Transactions the RPC is still
+    /// waiting on are polled for without using up any retries, and requests it didn't answer
+    /// before `--timeout` move on to the next RPC, until `--timeout` for every retry has passed.
+    pub async fn broadcast_tx(
+        &self,
+        signed_transaction: SignedTransaction,
+        wait_until: cli::WaitUntil,
+    ) -> eyre::Result<Option<FinalExecutionOutcomeView>> {
+        let tx_hash = signed_transaction.get_hash();
+        let sender_id = signed_transaction.transaction.signer_id.clone();
+
+        let wait_until = match wait_until {
+            cli::WaitUntil::Executed => {
+                let status = methods::tx::RpcTransactionStatusRequest {
+                    transaction_info: methods::tx::TransactionInfo::TransactionId {
+                        hash: tx_hash,
+                        account_id: sender_id.clone(),
+                    },
+                };
+                let submit = methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+                    signed_transaction,
+                };
+
+                return self
+                    .wait(&tx_hash, &sender_id, submit, status)
+                    .await
+                    .map(Some);
+            }
+            cli::WaitUntil::Included => "INCLUDED",
+            cli::WaitUntil::Final => "FINAL",
+            cli::WaitUntil::None => unreachable!("there's nothing to wait for"),
+        };
+
+        let status = methods::any::<Result<TxResponse, methods::tx::RpcTransactionError>>(
+            "tx",
+            json!({
+                "tx_hash": tx_hash,
+                "sender_account_id": &sender_id,
+                "wait_until": wait_until,
+            }),
+        );
+        let submit = methods::any::<Result<TxResponse, methods::tx::RpcTransactionError>>(
+            "send_tx",
+            json!({
+                "signed_tx_base64": near_primitives::serialize::to_base64(
+                    &borsh::BorshSerialize::try_to_vec(&signed_transaction)
+                        .expect("signed transactions are serializable"),
+                ),
+                "wait_until": wait_until,
+            }),
+        );
+
+        self.wait(&tx_hash, &sender_id, submit, status)
+            .await
+            .map(|response| response.outcome)
+    }
+
+    async fn wait<R, S, Q>(
+        &self,
+        tx_hash: &CryptoHash,
+        sender_id: &AccountId,
+        submit: S,
+        status: Q,
+    ) -> eyre::Result<R>
+    where
+        S: methods::RpcMethod<Response = R, Error = methods::tx::RpcTransactionError>,
+        Q: methods::RpcMethod<Response = R, Error = methods::tx::RpcTransactionError>,
+    {
+        let waiting = async {
+            // retries used up, and the RPC to send the next request through
+            let mut attempt = 0;
+            let mut rpc = 0;
+            let mut submitting = true;
+
+            loop {
+                let client = self.client(rpc);
+                let result = if submitting {
+                    client.call(&submit).await
+                } else {
+                    client.call(&status).await
+                };
+
+                match result {
+                    Err(JsonRpcError::ServerError(JsonRpcServerError::HandlerError(err))) => {
+                        match err {
+                            methods::tx::RpcTransactionError::UnknownTransaction { .. }
+                                if !submitting =>
+                            {
+                                submitting = true
+                            }
+                            methods::tx::RpcTransactionError::TimeoutError => {
+                                info!("transaction is still pending, polling its status");
+                                tokio::time::sleep(POLL_INTERVAL).await;
+                                submitting = false;
+                            }
+                            err => {
+                                let err = JsonRpcError::ServerError(
+                                    JsonRpcServerError::HandlerError(err),
+                                );
+                                if attempt < self.retries && is_transient(&err) {
+                                    self.backoff(attempt, rpc, &err).await;
+                                    attempt += 1;
+                                    rpc += 1;
+                                    submitting = false;
+                                } else {
+                                    return Err(err);
+                                }
+                            }
+                        }
+                    }
+                    Err(err) if is_timeout(&err) => {
+                        rpc += 1;
+                        info!(
+                            "{}, polling the transaction status through {}",
+                            err,
+                            self.client(rpc).server_addr()
+                        );
+                        submitting = false;
+                    }
+                    Err(err) if attempt < self.retries && is_transient(&err) => {
+                        self.backoff(attempt, rpc, &err).await;
+                        attempt += 1;
+                        rpc += 1;
+                        submitting = false;
+                    }
+                    result => return result,
+                }
+            }
+        };
+
+        match tokio::time::timeout(self.tx_timeout, waiting).await {
+            Ok(result) => Ok(result?),
+            Err(_) => eyre::bail!(
+                "gave up waiting for transaction {} after {}s, it may still be executed, \
+                look it up later with `nearx status {} from {}`",
+                tx_hash,
+                self.tx_timeout.as_secs(),
+                tx_hash,
+                sender_id
+            ),
+        }
+    }
+
+    fn client(&self, rpc: u32) -> &JsonRpcClient {
+        &self.clients[rpc as usize % self.clients.len()]
+    }
+
+    /// Waits before the next attempt, which goes to the RPC after `rpc`.
+    async fn backoff<E: std::fmt::Display>(&self, attempt: u32, rpc: u32, err: &E) {
+        let delay = INITIAL_BACKOFF
+            .saturating_mul(1 << attempt.min(16))
+            .min(MAX_BACKOFF);
+
+        warn!(
+            "{}, retrying through {} in {:.1}s ({}/{})",
+            err,
+            self.client(rpc + 1).server_addr(),
+            delay.as_secs_f64(),
+            attempt + 1,
+            self.retries
+        );
+
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// The response of `send_tx` and `tx`, which only include the outcome once it's executed.
+#[derive(Deserialize)]
+struct TxResponse {
+    #[serde(flatten)]
+    outcome: Option<FinalExecutionOutcomeView>,
+}
+
+impl methods::RpcHandlerResponse for TxResponse {}
+
+/// Errors that may go away if the request is retried, possibly against another RPC.
+pub trait Transient: std::fmt::Display {
+    fn is_transient(&self) -> bool;
+}
+
+impl Transient for methods::query::RpcQueryError {
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::NoSyncedBlocks | Self::UnavailableShard { .. } | Self::InternalError { .. }
+        )
+    }
+}
+
+impl Transient for methods::tx::RpcTransactionError {
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::TimeoutError | Self::DoesNotTrackShard | Self::InternalError { .. }
+        )
+    }
+}
+
+impl Transient for methods::EXPERIMENTAL_protocol_config::RpcProtocolConfigError {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::InternalError { .. })
+    }
+}
+
+impl Transient for methods::broadcast_tx_async::RpcBroadcastTxAsyncError {
+    fn is_transient(&self) -> bool {
+        match *self {}
+    }
+}
+
+/// Requests that reached the RPC, but that it didn't answer before `--timeout`.
+fn is_timeout<E>(err: &JsonRpcError<E>) -> bool {
+    match err {
+        JsonRpcError::TransportError(RpcTransportError::SendError(
+            JsonRpcTransportSendError::PayloadSendError(err),
+        ))
+        | JsonRpcError::TransportError(RpcTransportError::RecvError(
+            JsonRpcTransportRecvError::PayloadRecvError(err),
+        )) => err.is_timeout() && !err.is_connect(),
+        _ => false,
+    }
+}
+
+/// Connection failures, timeouts, rate limits and 5xx responses.
+fn is_transient<E: Transient>(err: &JsonRpcError<E>) -> bool {
+    match err {
+        JsonRpcError::TransportError(RpcTransportError::SendError(
+            JsonRpcTransportSendError::PayloadSendError(_),
+        ))
+        | JsonRpcError::TransportError(RpcTransportError::RecvError(
+            JsonRpcTransportRecvError::PayloadRecvError(_),
+        )) => true,
+        JsonRpcError::ServerError(JsonRpcServerError::InternalError { .. }) => true,
+        JsonRpcError::ServerError(JsonRpcServerError::ResponseStatusError(
+            JsonRpcServerResponseStatusError::TooManyRequests,
+        )) => true,
+        JsonRpcError::ServerError(JsonRpcServerError::ResponseStatusError(
+            JsonRpcServerResponseStatusError::Unexpected { status },
+        )) => status.is_server_error(),
+        JsonRpcError::ServerError(JsonRpcServerError::HandlerError(err)) => err.is_transient(),
+        _ => false,
+    }
+}
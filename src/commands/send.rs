@@ -0,0 +1,30 @@
+use color_eyre::eyre;
+
+use crate::cli;
+
+use super::transaction::transact;
+
+pub async fn run(command: cli::SendCommand, options: &cli::Options) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let signer =
+        near_crypto::InMemorySigner::from_secret_key(command.signer_id, command.secret_key);
+
+    let actions = vec![near_primitives::transaction::Action::Transfer(
+        near_primitives::transaction::TransferAction {
+            deposit: command.amount,
+        },
+    )];
+
+    transact(
+        client,
+        signer,
+        command.receiver,
+        actions,
+        command.action,
+        options,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use color_eyre::eyre;
+use serde_json::json;
+
+use near_jsonrpc_client::methods;
+use near_jsonrpc_primitives::types::query::QueryResponseKind;
+use near_primitives::serialize::to_base64;
+use near_primitives::views::QueryRequest;
+
+use crate::cli;
+use crate::exit::{ExitCode, WithExitCode};
+use crate::macros::info;
+
+pub async fn run(command: cli::StateCommand) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    let request = methods::query::RpcQueryRequest {
+        block_reference: command.block,
+        request: QueryRequest::ViewState {
+            account_id: command.contract_id.clone(),
+            prefix: command.prefix.clone(),
+            include_proof: false,
+        },
+    };
+
+    let response = client.call(request).await.exit_code(ExitCode::Rpc)?;
+
+    let state = match response.kind {
+        QueryResponseKind::ViewState(state) => state,
+        err => unreachable!("unexpected response kind: {:?}", err),
+    };
+
+    if command.json {
+        let document = json!({
+            "account_id": command.contract_id,
+            "block_hash": response.block_hash,
+            "block_height": response.block_height,
+            "prefix_base64": to_base64(&command.prefix),
+            "values": state
+                .values
+                .iter()
+                .map(|item| json!({
+                    "key": utf8(&item.key),
+                    "key_base64": to_base64(&item.key),
+                    "value": utf8(&item.value),
+                    "value_base64": to_base64(&item.value),
+                }))
+                .collect::<Vec<_>>(),
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&document).expect("json is valid")
+        );
+        return Ok(());
+    }
+
+    info!("    block height: \x1b[1m{}\x1b[0m", response.block_height);
+    info!("      block hash: \x1b[1m{}\x1b[0m", response.block_hash);
+    info!("         entries: \x1b[1m{}\x1b[0m", state.values.len());
+
+    for item in &state.values {
+        println!("{}\t{}", display(&item.key), display(&item.value));
+    }
+
+    Ok(())
+}
+
+/// The bytes as a string, if they're valid UTF-8 that fits on a single line.
+fn utf8(bytes: &[u8]) -> Option<&str> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .filter(|s| !s.chars().any(char::is_control))
+}
+
+/// The bytes as a quoted string when possible, or else as hex prefixed with `0x`, like prefixes
+/// are read, so that text starting with `0x` can't be mistaken for bytes.
+fn display(bytes: &[u8]) -> String {
+    match utf8(bytes) {
+        Some(s) => serde_json::to_string(s).expect("strings are valid json"),
+        None => std::iter::once("0x".to_owned())
+            .chain(bytes.iter().map(|byte| format!("{:02x}", byte)))
+            .collect(),
+    }
+}
@@ -0,0 +1,40 @@
+use color_eyre::eyre;
+
+use near_jsonrpc_client::methods;
+
+use crate::cli;
+use crate::exit::{ExitCode, WithExitCode};
+use crate::macros::info;
+
+use super::transaction::{report, report_explorer_link, report_with_receipts};
+
+pub async fn run(command: cli::StatusCommand, options: &cli::Options) -> eyre::Result<()> {
+    let client = super::connect(&command.rpc)?;
+
+    info!("transaction hash: \x1b[1m{}\x1b[0m", command.tx_hash);
+    report_explorer_link(&command.tx_hash, options);
+
+    let transaction_info = methods::tx::TransactionInfo::TransactionId {
+        hash: command.tx_hash,
+        account_id: command.sender_id,
+    };
+
+    match options.receipts {
+        None => {
+            let request = methods::tx::RpcTransactionStatusRequest { transaction_info };
+            report(
+                client.call(request).await.exit_code(ExitCode::Rpc)?,
+                options.json,
+            )
+        }
+        Some(format) => {
+            let request =
+                methods::EXPERIMENTAL_tx_status::RpcTransactionStatusRequest { transaction_info };
+            report_with_receipts(
+                client.call(request).await.exit_code(ExitCode::Rpc)?,
+                format,
+                options.json,
+            )
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use borsh::BorshSerialize;
+use color_eyre::eyre;
+use serde_json::json;
+
+use near_account_id::AccountId;
+use near_crypto::InMemorySigner;
+use near_jsonrpc_client::methods;
+use near_primitives::hash::CryptoHash;
+use near_primitives::transaction::{Action, SignedTransaction};
+use near_primitives::types::{Balance, Gas, Nonce};
+use near_primitives::views::{
+    FinalExecutionOutcomeView, FinalExecutionOutcomeWithReceiptView, FinalExecutionStatus,
+};
+
+use crate::exit::{ExitCode, Reported, WithExitCode};
+use crate::macros::{error, info, log, warn};
+use crate::{cli, utils};
+
+use super::{receipts, rpc};
+
+pub async fn transact(
+    client: rpc::Client,
+    signer: InMemorySigner,
+    receiver_id: AccountId,
+    actions: Vec<Action>,
+    action: cli::CallAction,
+    options: &cli::Options,
+) -> eyre::Result<()> {
+    let (nonce, block_hash) = match action {
+        cli::CallAction::Offline { nonce, block_hash } => (nonce, block_hash),
+        cli::CallAction::Display | cli::CallAction::Submit => {
+            next_nonce(&client, &signer, &receiver_id, &actions).await?
+        }
+    };
+
+    let transaction = near_primitives::transaction::Transaction {
+        signer_id: signer.account_id.clone(),
+        public_key: signer.public_key.clone(),
+        nonce,
+        block_hash,
+        receiver_id,
+        actions,
+    };
+
+    let signed_transaction = transaction.sign(&signer);
+
+    info!(
+        "transaction hash: \x1b[1m{}\x1b[0m",
+        signed_transaction.get_hash()
+    );
+
+    if let cli::CallAction::Display | cli::CallAction::Offline { .. } = action {
+        let encoded = near_primitives::serialize::base64_display(&signed_transaction.try_to_vec()?)
+            .to_string();
+
+        if options.json {
+            let document = json!({
+                "hash": signed_transaction.get_hash(),
+                "signed_transaction": encoded,
+            });
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&document).expect("json is valid")
+            );
+        } else {
+            println!("{}", encoded);
+        }
+
+        return Ok(());
+    }
+
+    broadcast(&client, signed_transaction, options).await
+}
+
+/// Submits a signed transaction and waits as long as `options.wait` says, reporting the outcome.
+pub async fn broadcast(
+    client: &rpc::Client,
+    signed_transaction: SignedTransaction,
+    options: &cli::Options,
+) -> eyre::Result<()> {
+    let tx_hash = signed_transaction.get_hash();
+    let sender_id = signed_transaction.transaction.signer_id.clone();
+
+    report_explorer_link(&tx_hash, options);
+
+    if let cli::WaitUntil::None = options.wait {
+        let request =
+            methods::broadcast_tx_async::RpcBroadcastTxAsyncRequest { signed_transaction };
+
+        let tx_hash = client.call(request).await.exit_code(ExitCode::Rpc)?;
+        return report_pending(&tx_hash, "submitted", options.json);
+    }
+
+    let Some(response) = client
+        .broadcast_tx(signed_transaction, options.wait)
+        .await
+        .exit_code(ExitCode::Rpc)?
+    else {
+        return report_pending(&tx_hash, "included", options.json);
+    };
+
+    let Some(format) = options.receipts else {
+        return report(response, options.json);
+    };
+
+    // the commit response doesn't include the receipts themselves, which we need to describe them
+    let request = methods::EXPERIMENTAL_tx_status::RpcTransactionStatusRequest {
+        transaction_info: methods::EXPERIMENTAL_tx_status::TransactionInfo::TransactionId {
+            hash: tx_hash,
+            account_id: sender_id,
+        },
+    };
+
+    report_with_receipts(
+        client.call(request).await.exit_code(ExitCode::Rpc)?,
+        format,
+        options.json,
+    )
+}
+
+/// Reports a transaction that hasn't been waited on until it was executed, printing its hash.
+fn report_pending(tx_hash: &CryptoHash, status: &str, json: bool) -> eyre::Result<()> {
+    info!("transaction {}, not waiting for it to be executed", status);
+
+    if json {
+        let document = json!({
+            "hash": tx_hash,
+            "status": status,
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&document).expect("json is valid")
+        );
+    } else {
+        println!("{}", tx_hash);
+    }
+
+    Ok(())
+}
+
+/// Links the transaction on the explorer of the network, if it has one.
+pub fn report_explorer_link(tx_hash: &CryptoHash, options: &cli::Options) {
+    if let Some(explorer_url) = &options.explorer_url {
+        info!(
+            "        explorer: \x1b[1m{}/txns/{}\x1b[0m",
+            explorer_url.trim_end_matches('/'),
+            tx_hash
+        );
+    }
+}
+
+/// Reports the outcome of an executed transaction.
+///
+/// With `json`, the outcome is printed as a single JSON document on stdout instead.
+pub fn report(response: FinalExecutionOutcomeView, json: bool) -> eyre::Result<()> {
+    report_cost(&response);
+
+    if json {
+        print_json(&response, None);
+        return report_status(response.status, false);
+    }
+
+    let outcomes = std::iter::once(("transaction", &response.transaction_outcome)).chain(
+        response
+            .receipts_outcome
+            .iter()
+            .map(|outcome| ("receipt", outcome)),
+    );
+
+    for (kind, outcome) in outcomes {
+        let logs = &outcome.outcome.logs;
+        if logs.is_empty() {
+            continue;
+        }
+
+        info!(
+            "logs from \x1b[1m{}\x1b[0m ({} {}):",
+            outcome.outcome.executor_id, kind, outcome.id
+        );
+
+        for (idx, log) in logs.iter().enumerate() {
+            log!(
+                "#{:>count$}\x1b[0m │ {}",
+                idx + 1,
+                log,
+                count = logs.len().to_string().len()
+            );
+        }
+    }
+
+    report_status(response.status, true)
+}
+
+/// Reports the outcome of an executed transaction along with its receipt tree.
+pub fn report_with_receipts(
+    response: FinalExecutionOutcomeWithReceiptView,
+    format: cli::ReceiptsFormat,
+    json: bool,
+) -> eyre::Result<()> {
+    report_cost(&response.final_outcome);
+
+    let receipts = match format {
+        cli::ReceiptsFormat::Tree => {
+            receipts::render(&response);
+            None
+        }
+        cli::ReceiptsFormat::Json => Some(receipts::to_json(&response)),
+    };
+
+    if json {
+        print_json(&response.final_outcome, receipts);
+    } else if let Some(receipts) = receipts {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&receipts).expect("json is valid")
+        );
+    }
+
+    let print_value = !json && matches!(format, cli::ReceiptsFormat::Tree);
+
+    report_status(response.final_outcome.status, print_value)
+}
+
+fn total_cost(response: &FinalExecutionOutcomeView) -> (Gas, Balance) {
+    std::iter::once(&response.transaction_outcome)
+        .chain(&response.receipts_outcome)
+        .fold((0u64, 0u128), |(gas, tokens), o| {
+            (
+                gas.saturating_add(o.outcome.gas_burnt),
+                tokens.saturating_add(o.outcome.tokens_burnt),
+            )
+        })
+}
+
+fn report_cost(response: &FinalExecutionOutcomeView) {
+    info!(
+        "      block hash: \x1b[1m{}\x1b[0m",
+        response.transaction_outcome.block_hash
+    );
+
+    let (gas_burnt, tokens_burnt) = total_cost(response);
+
+    info!(
+        "  execution cost: \x1b[1m{:.4} TGas\x1b[0m",
+        gas_burnt as f64 / utils::TGAS as f64
+    );
+    info!(
+        "    tokens burnt: \x1b[1m{}\x1b[0m",
+        utils::format_balance(tokens_burnt)
+    );
+}
+
+fn report_status(status: FinalExecutionStatus, print_value: bool) -> eyre::Result<()> {
+    match status {
+        FinalExecutionStatus::SuccessValue(result) => {
+            if print_value {
+                utils::print_result(&result);
+            }
+        }
+        FinalExecutionStatus::Failure(error) => {
+            error!("transaction failed: {:#?}", error);
+            return Err(Reported(ExitCode::of_tx_error(&error)).into());
+        }
+        FinalExecutionStatus::NotStarted | FinalExecutionStatus::Started => {
+            warn!("transaction has not finished executing yet");
+        }
+    }
+
+    Ok(())
+}
+
+fn print_json(response: &FinalExecutionOutcomeView, receipts: Option<serde_json::Value>) {
+    let (gas_burnt, tokens_burnt) = total_cost(response);
+
+    let logs = std::iter::once(&response.transaction_outcome)
+        .chain(&response.receipts_outcome)
+        .flat_map(|outcome| {
+            outcome.outcome.logs.iter().map(|log| {
+                json!({
+                    "receipt_id": outcome.id,
+                    "executor_id": outcome.outcome.executor_id,
+                    "log": log,
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let (status, result, error) = match &response.status {
+        FinalExecutionStatus::SuccessValue(result) => {
+            ("success", utils::result_to_json(result), None)
+        }
+        FinalExecutionStatus::Failure(error) => ("failure", serde_json::Value::Null, Some(error)),
+        FinalExecutionStatus::NotStarted | FinalExecutionStatus::Started => {
+            ("pending", serde_json::Value::Null, None)
+        }
+    };
+
+    let mut document = json!({
+        "hash": response.transaction_outcome.id,
+        "block_hash": response.transaction_outcome.block_hash,
+        "gas_burnt": gas_burnt,
+        "tokens_burnt": tokens_burnt.to_string(),
+        "logs": logs,
+        "status": status,
+        "result": result,
+        "error": error,
+    });
+
+    if let Some(receipts) = receipts {
+        document["receipts"] = receipts;
+    }
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&document).expect("json is valid")
+    );
+}
+
+/// Looks up the signer's access key, returning the nonce to use and a recent block hash.
+///
+/// Fails if the access key isn't permitted to perform the actions.
+async fn next_nonce(
+    client: &rpc::Client,
+    signer: &InMemorySigner,
+    receiver_id: &AccountId,
+    actions: &[Action],
+) -> eyre::Result<(Nonce, CryptoHash)> {
+    let access_key_request = methods::query::RpcQueryRequest {
+        block_reference: near_primitives::types::BlockReference::latest(),
+        request: near_primitives::views::QueryRequest::ViewAccessKey {
+            account_id: signer.account_id.clone(),
+            public_key: signer.public_key.clone(),
+        },
+    };
+
+    let methods::query::RpcQueryResponse {
+        block_hash,
+        kind: query_response_kind,
+        ..
+    } = client
+        .call(access_key_request)
+        .await
+        .exit_code(ExitCode::Rpc)?;
+
+    let near_primitives::views::AccessKeyView { permission, nonce } = match query_response_kind {
+        near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(result) => result,
+        err => unreachable!("unexpected response kind: {:?}", err),
+    };
+
+    match permission {
+        near_primitives::views::AccessKeyPermissionView::FullAccess => {}
+        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            receiver_id: allowed_receiver_id,
+            method_names,
+            ..
+        } => {
+            for action in actions {
+                let Action::FunctionCall(call) = action else {
+                    error!("access key only has permission to call functions");
+                    return Err(Reported(ExitCode::PermissionDenied).into());
+                };
+
+                if allowed_receiver_id != receiver_id.as_str() {
+                    error!("access key does not have permission to call this contract");
+                    return Err(Reported(ExitCode::PermissionDenied).into());
+                }
+
+                if !(method_names.is_empty() || method_names.contains(&call.method_name)) {
+                    error!("access key does not have permission to call this method");
+                    return Err(Reported(ExitCode::PermissionDenied).into());
+                }
+
+                if call.deposit > 0 {
+                    error!("access key can only call functions without attaching a deposit");
+                    return Err(Reported(ExitCode::PermissionDenied).into());
+                }
+            }
+        }
+    }
+
+    Ok((nonce + 1, block_hash))
+}
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::path::PathBuf;
+
+use color_eyre::eyre;
+use serde::{Deserialize, Deserializer};
+
+use near_account_id::AccountId;
+use near_primitives::types::Gas;
+
+use crate::utils;
+
+/// The contents of `~/.config/nearx/config.toml`.
+///
+/// ```toml
+/// # the network to use when none is specified
+/// network = "testnet"
+///
+/// [networks.testnet]
+/// rpc_url = "https://rpc.testnet.near.org"
+/// network_id = "testnet"
+/// fallback_rpc_urls = ["https://near-testnet.example.com"]
+/// retries = 5
+/// timeout = 30
+/// api_key = "..."
+/// headers = { "Authorization" = "Bearer ..." }
+/// signer = "bob.testnet"
+/// gas = "100Tgas"
+/// explorer_url = "https://testnet.nearblocks.io"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The network to use when none is specified.
+    pub network: Option<String>,
+    #[serde(default)]
+    networks: BTreeMap<String, Network>,
+}
+
+/// A named network, selected with `through NAME` or `NEARX_NETWORK`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Network {
+    pub rpc_url: Option<String>,
+    /// Where keys are looked up, i.e. `~/.near-credentials/<NETWORK_ID>` and the keystore
+    /// directory of the same name. Defaults to the name of the network.
+    pub network_id: Option<String>,
+    /// Tried in turn when `rpc_url` fails.
+    #[serde(default)]
+    pub fallback_rpc_urls: Vec<String>,
+    /// How many times to retry transient RPC failures.
+    pub retries: Option<u32>,
+    /// How many seconds to wait for a response before giving up on a request.
+    pub timeout: Option<u64>,
+    /// An API key to send as `x-api-key`.
+    pub api_key: Option<String>,
+    /// Headers to send with every request, e.g. for providers that don't use `x-api-key`.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The account to sign with when `as ACCOUNT` is omitted.
+    pub signer: Option<AccountId>,
+    /// The gas to attach to function calls when `gas GAS` is omitted.
+    #[serde(default, deserialize_with = "gas")]
+    pub gas: Option<Gas>,
+    /// Where to look up transactions, e.g. `https://nearblocks.io`.
+    pub explorer_url: Option<String>,
+}
+
+/// The networks that are known without any configuration.
+const BUILTIN_NETWORKS: &[(&str, &str, Option<&str>)] = &[
+    (
+        "mainnet",
+        "https://rpc.mainnet.near.org",
+        Some("https://nearblocks.io"),
+    ),
+    (
+        "testnet",
+        "https://rpc.testnet.near.org",
+        Some("https://testnet.nearblocks.io"),
+    ),
+    ("localnet", "http://localhost:3030", None),
+];
+
+impl Config {
+    /// Reads the config file, if there is one.
+    pub fn load() -> eyre::Result<Self> {
+        let Some(path) = path() else {
+            return Ok(Config::default());
+        };
+
+        if !path.is_file() {
+            return Ok(Config::default());
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|err| eyre::eyre!("failed to read `{}`: {}", path.display(), err))?;
+
+        toml::from_str(&contents)
+            .map_err(|err| eyre::eyre!("invalid config file `{}`: {}", path.display(), err))
+    }
+
+    /// Looks up a network by name, layering the config file over the built-in networks.
+    pub fn network(&self, name: &str) -> Option<Network> {
+        let builtin = BUILTIN_NETWORKS
+            .iter()
+            .find(|(builtin, ..)| *builtin == name)
+            .map(|(_, rpc_url, explorer_url)| Network {
+                rpc_url: Some((*rpc_url).to_owned()),
+                network_id: Some(name.to_owned()),
+                explorer_url: explorer_url.map(str::to_owned),
+                ..Network::default()
+            });
+
+        let network = match (self.networks.get(name).cloned(), builtin) {
+            (Some(network), Some(builtin)) => Network {
+                rpc_url: network.rpc_url.or(builtin.rpc_url),
+                explorer_url: network.explorer_url.or(builtin.explorer_url),
+                ..network
+            },
+            (network, builtin) => network.or(builtin)?,
+        };
+
+        Some(Network {
+            network_id: network.network_id.or_else(|| Some(name.to_owned())),
+            ..network
+        })
+    }
+}
+
+/// The config file, `~/.config/nearx/config.toml` unless `NEARX_CONFIG` is set.
+pub fn path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("NEARX_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+            Some(PathBuf::from(home).join(".config"))
+        })?;
+
+    Some(config_dir.join("nearx").join("config.toml"))
+}
+
+/// Accepts either raw gas units or a string like `"100Tgas"`.
+fn gas<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Gas>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawGas {
+        Units(Gas),
+        Amount(String),
+    }
+
+    match RawGas::deserialize(deserializer)? {
+        RawGas::Units(gas) => Ok(Some(gas)),
+        RawGas::Amount(gas) => utils::parse_gas(&gas)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
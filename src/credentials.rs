@@ -0,0 +1,323 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre;
+use serde::Deserialize;
+
+use near_account_id::AccountId;
+use near_crypto::SecretKey;
+
+/// The key file format used by near-cli.
+#[derive(Deserialize)]
+struct KeyFile {
+    #[serde(alias = "secret_key")]
+    private_key: SecretKey,
+}
+
+/// The HD derivation path NEAR wallets use by default.
+const DEFAULT_HD_PATH: &str = "m/44'/397'/0'";
+
+/// Parses a secret key or a seed phrase, or reads a key from the file at that path.
+///
+/// Seed phrases may end with an HD derivation path, e.g. `"<WORDS> m/44'/397'/1'"`.
+/// Files can either hold the bare key or be in the near-cli JSON format.
+pub fn secret_key_from_str(s: &str) -> eyre::Result<SecretKey> {
+    if let Ok(secret_key) = s.parse() {
+        return Ok(secret_key);
+    }
+
+    if s.trim().contains(char::is_whitespace) {
+        return secret_key_from_seed_phrase(s);
+    }
+
+    let path = Path::new(s);
+    if !path.is_file() {
+        eyre::bail!(
+            "`{}` is neither a secret key, a seed phrase nor a readable file",
+            s
+        );
+    }
+
+    read_key_file(path)
+}
+
+/// Derives an ed25519 key from a BIP-39 seed phrase, following SLIP-10.
+fn secret_key_from_seed_phrase(s: &str) -> eyre::Result<SecretKey> {
+    let mut words = s.split_whitespace().collect::<Vec<_>>();
+    let hd_path = match words.last() {
+        Some(word) if word.starts_with("m/") => words.pop().expect("there's a last word"),
+        _ => DEFAULT_HD_PATH,
+    };
+
+    let mnemonic = bip39::Mnemonic::parse_normalized(&words.join(" "))
+        .map_err(|err| eyre::eyre!("invalid seed phrase: {}", err))?;
+
+    let mut key = slip10_ed25519(&mnemonic.to_seed(""), &parse_hd_path(hd_path)?);
+
+    let secret = ed25519_dalek::SecretKey::from_bytes(&key)
+        .map_err(|err| eyre::eyre!("invalid derived key: {}", err))?;
+    let public = ed25519_dalek::PublicKey::from(&secret);
+    key.fill(0);
+
+    let mut keypair = [0; ed25519_dalek::KEYPAIR_LENGTH];
+    keypair[..ed25519_dalek::SECRET_KEY_LENGTH].copy_from_slice(secret.as_bytes());
+    keypair[ed25519_dalek::SECRET_KEY_LENGTH..].copy_from_slice(public.as_bytes());
+
+    Ok(SecretKey::ED25519(near_crypto::ED25519SecretKey(keypair)))
+}
+
+/// Parses a path like `m/44'/397'/0'`, where every index has to be hardened.
+fn parse_hd_path(path: &str) -> eyre::Result<Vec<u32>> {
+    let Some(indices) = path.strip_prefix("m/") else {
+        eyre::bail!("invalid derivation path `{}`, expected `m/...`", path);
+    };
+
+    indices
+        .split('/')
+        .map(|index| {
+            let Some(index) = index.strip_suffix(['\'', 'h']) else {
+                eyre::bail!(
+                    "ed25519 only supports hardened derivation, use `{}'` in `{}`",
+                    index,
+                    path
+                );
+            };
+
+            match index.parse::<u32>() {
+                Ok(index) if index < 1 << 31 => Ok(index | 1 << 31),
+                _ => eyre::bail!("invalid index `{}` in derivation path `{}`", index, path),
+            }
+        })
+        .collect()
+}
+
+fn slip10_ed25519(seed: &[u8], path: &[u32]) -> [u8; 32] {
+    use hmac::{Hmac, Mac};
+
+    let hmac = |key: &[u8], data: &[&[u8]]| {
+        let mut mac = Hmac::<sha2::Sha512>::new_from_slice(key).expect("hmac accepts any key");
+        for data in data {
+            mac.update(data);
+        }
+        mac.finalize().into_bytes()
+    };
+
+    let mut output = hmac(b"ed25519 seed", &[seed]);
+
+    for index in path {
+        let (key, chain_code) = output.split_at(32);
+        output = hmac(chain_code, &[&[0], key, &index.to_be_bytes()]);
+    }
+
+    let mut key = [0; 32];
+    key.copy_from_slice(&output[..32]);
+    key
+}
+
+fn read_key_file(path: &Path) -> eyre::Result<SecretKey> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| eyre::eyre!("failed to read `{}`: {}", path.display(), err))?;
+    let contents = contents.trim();
+
+    if contents.starts_with('{') {
+        let key_file = serde_json::from_str::<KeyFile>(contents)
+            .map_err(|err| eyre::eyre!("invalid key file `{}`: {}", path.display(), err))?;
+        return Ok(key_file.private_key);
+    }
+
+    contents
+        .parse()
+        .map_err(|err| eyre::eyre!("invalid secret key in `{}`: {}", path.display(), err))
+}
+
+/// Describes a key pair in the near-cli key file format.
+pub fn key_file_json(account_id: Option<&AccountId>, secret_key: &SecretKey) -> serde_json::Value {
+    let mut key_file = serde_json::json!({
+        "public_key": secret_key.public_key(),
+        "private_key": secret_key,
+    });
+
+    if let Some(account_id) = account_id {
+        key_file["account_id"] = serde_json::json!(account_id);
+    }
+
+    key_file
+}
+
+/// Writes a key file in the near-cli format, refusing to overwrite an existing one.
+pub fn write_key_file(
+    path: &Path,
+    account_id: Option<&AccountId>,
+    secret_key: &SecretKey,
+) -> eyre::Result<()> {
+    if path.exists() {
+        eyre::bail!(
+            "`{}` already exists, refusing to overwrite it",
+            path.display()
+        );
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let file = options
+        .open(path)
+        .map_err(|err| eyre::eyre!("failed to create `{}`: {}", path.display(), err))?;
+
+    serde_json::to_writer(file, &key_file_json(account_id, secret_key))?;
+
+    Ok(())
+}
+
+/// Looks up the key for an account in `~/.near-credentials/<NETWORK>/<ACCOUNT>.json`.
+pub fn lookup(account_id: &AccountId, network: &str) -> eyre::Result<Option<SecretKey>> {
+    let Some(path) = credentials_path(account_id, network) else {
+        return Ok(None);
+    };
+
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    read_key_file(&path).map(Some)
+}
+
+/// Where the key for an account on a network is expected.
+pub fn credentials_path(account_id: &AccountId, network: &str) -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+
+    Some(
+        PathBuf::from(home)
+            .join(".near-credentials")
+            .join(network)
+            .join(format!("{}.json", account_id)),
+    )
+}
+
+/// The network an account is on, from `NEAR_ENV`, or guessed from the RPC URL and the account.
+pub fn network(account_id: &AccountId, rpc_url: &str) -> Option<String> {
+    if let Ok(network) = env::var("NEAR_ENV") {
+        return Some(network);
+    }
+
+    for network in ["mainnet", "testnet", "betanet"] {
+        if rpc_url.contains(network) {
+            return Some(network.to_owned());
+        }
+    }
+
+    match account_id.as_str().rsplit('.').next() {
+        Some("near") => Some("mainnet".to_owned()),
+        Some(network @ ("testnet" | "betanet")) => Some(network.to_owned()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn derives_the_slip10_test_vectors() {
+        // test vector 1 for ed25519 from SLIP-0010
+        let seed = (0..16).collect::<Vec<u8>>();
+
+        assert_eq!(
+            hex(&slip10_ed25519(&seed, &[])),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        for (path, key) in [
+            (
+                "m/0'",
+                "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+            ),
+            (
+                "m/0'/1'",
+                "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+            ),
+            (
+                "m/0'/1'/2'",
+                "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9",
+            ),
+            (
+                "m/0'/1'/2'/2'",
+                "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662",
+            ),
+            (
+                "m/0'/1'/2'/2'/1000000000'",
+                "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+            ),
+        ] {
+            assert_eq!(
+                hex(&slip10_ed25519(&seed, &parse_hd_path(path).unwrap())),
+                key,
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn derives_seed_phrases_on_the_default_path() {
+        // the BIP-39 seed of the phrase, as published with the reference implementation
+        let seed = bip39::Mnemonic::parse_normalized(MNEMONIC)
+            .unwrap()
+            .to_seed("");
+        assert_eq!(
+            hex(&seed),
+            "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc1\
+            9a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4"
+        );
+
+        // cross-checked with a separate implementation (Python's hashlib and `cryptography`),
+        // which also reproduces the SLIP-0010 vectors above
+        let secret_key = secret_key_from_str(MNEMONIC).unwrap();
+        let near_crypto::SecretKey::ED25519(keypair) = &secret_key else {
+            panic!("seed phrases derive ed25519 keys");
+        };
+        assert_eq!(
+            hex(&keypair.0[..32]),
+            "0c158d858a52316667d03d1d04aad51b3b542cd705215810629b78c501492fba"
+        );
+        assert_eq!(
+            secret_key.public_key().to_string(),
+            "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25"
+        );
+
+        assert_eq!(
+            secret_key_from_str(&format!("{} {}", MNEMONIC, DEFAULT_HD_PATH)).unwrap(),
+            secret_key
+        );
+        assert_ne!(
+            secret_key_from_str(&format!("{} m/44'/397'/1'", MNEMONIC)).unwrap(),
+            secret_key
+        );
+    }
+
+    #[test]
+    fn rejects_unhardened_paths() {
+        assert!(parse_hd_path("m/44'/397'/0").is_err());
+        assert!(secret_key_from_str(&format!("{} m/44'/397'/0", MNEMONIC)).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_paths_and_phrases() {
+        assert!(parse_hd_path("44'/397'/0'").is_err());
+        assert!(parse_hd_path("m/2147483648'").is_err());
+        assert!(secret_key_from_str("abandon abandon abandon").is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+
+use color_eyre::eyre;
+
+use near_jsonrpc_primitives::types::query::RpcQueryError;
+use near_primitives::errors::{ActionErrorKind, TxExecutionError};
+use near_vm_errors::FunctionCallErrorSer;
+
+/// The process exit codes, so scripts can branch on the outcome of a command.
+///
+/// Attach one to an error with [`WithExitCode::exit_code`], or return it as a [`Reported`]
+/// failure if the details have already been printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    /// Anything not covered below.
+    Failure = 1,
+    /// The command line, or a file or stream it refers to, is invalid.
+    InvalidInput = 2,
+    /// The RPC couldn't be reached, or it rejected the request.
+    Rpc = 3,
+    /// The access key isn't permitted to sign the transaction.
+    PermissionDenied = 4,
+    /// The transaction, or one of its actions, failed on chain.
+    ActionError = 5,
+    /// The called contract panicked, or otherwise aborted while executing.
+    ContractPanic = 6,
+    /// The called contract couldn't be executed, because it has no code, the code is invalid, or
+    /// the method doesn't exist.
+    ContractError = 7,
+}
+
+/// The kinds of `FunctionCallError` raised before the contract starts executing.
+const CONTRACT_ERRORS: [&str; 3] = ["CompilationError", "LinkError", "MethodResolveError"];
+
+impl ExitCode {
+    /// Classifies a failed transaction.
+    pub fn of_tx_error(error: &TxExecutionError) -> Self {
+        match error {
+            TxExecutionError::ActionError(error) => match error.kind {
+                ActionErrorKind::FunctionCallError(
+                    FunctionCallErrorSer::CompilationError(_)
+                    | FunctionCallErrorSer::LinkError { .. }
+                    | FunctionCallErrorSer::MethodResolveError(_),
+                ) => ExitCode::ContractError,
+                ActionErrorKind::FunctionCallError(_) => ExitCode::ContractPanic,
+                _ => ExitCode::ActionError,
+            },
+            TxExecutionError::InvalidTxError(_) => ExitCode::ActionError,
+        }
+    }
+
+    /// Classifies a failed view call, whose VM errors the RPC only describes as text.
+    pub fn of_query_error(error: &RpcQueryError) -> Self {
+        match error {
+            RpcQueryError::NoContractCode { .. } => ExitCode::ContractError,
+            RpcQueryError::ContractExecutionError { vm_error, .. }
+                if CONTRACT_ERRORS.iter().any(|kind| vm_error.contains(kind)) =>
+            {
+                ExitCode::ContractError
+            }
+            RpcQueryError::ContractExecutionError { .. } => ExitCode::ContractPanic,
+            _ => ExitCode::Rpc,
+        }
+    }
+
+    /// Returns the code the process should exit with after this error.
+    pub fn of(report: &eyre::Report) -> Self {
+        report
+            .chain()
+            .find_map(|error| match error.downcast_ref() {
+                Some(Reported(code)) => Some(*code),
+                None => error.downcast_ref::<Coded>().map(|coded| coded.code),
+            })
+            .unwrap_or(ExitCode::Failure)
+    }
+}
+
+impl fmt::Display for ExitCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ExitCode::Failure => "command failed",
+            ExitCode::InvalidInput => "invalid input",
+            ExitCode::Rpc => "RPC request failed",
+            ExitCode::PermissionDenied => "permission denied",
+            ExitCode::ActionError => "transaction failed",
+            ExitCode::ContractPanic => "contract panicked",
+            ExitCode::ContractError => "contract couldn't be executed",
+        })
+    }
+}
+
+/// A failure whose details have already been printed.
+#[derive(Debug)]
+pub struct Reported(pub ExitCode);
+
+impl fmt::Display for Reported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Reported {}
+
+/// Attaches an exit code to the errors of a result, keeping their message.
+pub trait WithExitCode<T> {
+    fn exit_code(self, code: ExitCode) -> eyre::Result<T>;
+}
+
+impl<T, E: Into<eyre::Report>> WithExitCode<T> for Result<T, E> {
+    #[track_caller]
+    fn exit_code(self, code: ExitCode) -> eyre::Result<T> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(eyre::Report::new(Coded {
+                code,
+                report: error.into(),
+            })),
+        }
+    }
+}
+
+/// An error with the code to exit with, displayed as the error itself.
+#[derive(Debug)]
+struct Coded {
+    code: ExitCode,
+    report: eyre::Report,
+}
+
+impl fmt::Display for Coded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.report, f)
+    }
+}
+
+impl std::error::Error for Coded {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.report.source()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_primitives::errors::ActionError;
+    use near_vm_errors::{MethodResolveError, WasmTrap};
+
+    use super::*;
+
+    fn function_call_error(error: FunctionCallErrorSer) -> TxExecutionError {
+        TxExecutionError::ActionError(ActionError {
+            index: Some(0),
+            kind: ActionErrorKind::FunctionCallError(error),
+        })
+    }
+
+    #[test]
+    fn separates_panics_from_contracts_that_cant_run() {
+        for error in [
+            FunctionCallErrorSer::WasmTrap(WasmTrap::Unreachable),
+            FunctionCallErrorSer::ExecutionError("Smart contract panicked: boom".to_owned()),
+        ] {
+            assert_eq!(
+                ExitCode::of_tx_error(&function_call_error(error)),
+                ExitCode::ContractPanic
+            );
+        }
+        assert_eq!(
+            ExitCode::of_tx_error(&function_call_error(
+                FunctionCallErrorSer::MethodResolveError(MethodResolveError::MethodNotFound)
+            )),
+            ExitCode::ContractError
+        );
+        assert_eq!(
+            ExitCode::of_query_error(&RpcQueryError::ContractExecutionError {
+                vm_error: "wasm execution failed with error: MethodResolveError(MethodNotFound)"
+                    .to_owned(),
+                block_height: 1,
+                block_hash: Default::default(),
+            }),
+            ExitCode::ContractError
+        );
+        assert_eq!(
+            ExitCode::of_query_error(&RpcQueryError::ContractExecutionError {
+                vm_error: "wasm execution failed with error: HostError(GuestPanic { .. })"
+                    .to_owned(),
+                block_height: 1,
+                block_hash: Default::default(),
+            }),
+            ExitCode::ContractPanic
+        );
+    }
+}
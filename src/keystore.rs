@@ -0,0 +1,393 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::XChaCha20Poly1305;
+use color_eyre::eyre;
+use serde::{Deserialize, Serialize};
+
+use near_account_id::AccountId;
+use near_crypto::{PublicKey, SecretKey};
+use near_primitives::serialize::{from_base64, to_base64};
+
+/// scrypt cost parameters for newly encrypted keys.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// The most expensive scrypt parameters accepted from a keystore file, so a tampered one can't
+/// exhaust the memory or the time of whoever decrypts it.
+const MAX_SCRYPT_LOG_N: u8 = 20;
+const MAX_SCRYPT_R: u32 = 32;
+const MAX_SCRYPT_P: u32 = 16;
+const MAX_SCRYPT_MEMORY: u64 = 256 * 1024 * 1024;
+
+/// A secret key, encrypted with a key derived from a passphrase.
+#[derive(Serialize, Deserialize)]
+pub struct EncryptedKey {
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+    kdf: Kdf,
+    cipher: Cipher,
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "lowercase")]
+enum Kdf {
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+        salt: String,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "lowercase")]
+enum Cipher {
+    XChaCha20Poly1305 { nonce: String },
+}
+
+impl EncryptedKey {
+    pub fn encrypt(
+        account_id: AccountId,
+        secret_key: &SecretKey,
+        passphrase: &str,
+    ) -> eyre::Result<Self> {
+        let mut salt = [0; 16];
+        chacha20poly1305::aead::rand_core::RngCore::fill_bytes(&mut OsRng, &mut salt);
+
+        let cipher = XChaCha20Poly1305::new(&derive_key(
+            passphrase,
+            &salt,
+            SCRYPT_LOG_N,
+            SCRYPT_R,
+            SCRYPT_P,
+        )?);
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let ciphertext = cipher
+            .encrypt(&nonce, secret_key.to_string().as_bytes())
+            .map_err(|_| eyre::eyre!("failed to encrypt the secret key"))?;
+
+        Ok(Self {
+            account_id,
+            public_key: secret_key.public_key(),
+            kdf: Kdf::Scrypt {
+                log_n: SCRYPT_LOG_N,
+                r: SCRYPT_R,
+                p: SCRYPT_P,
+                salt: to_base64(&salt),
+            },
+            cipher: Cipher::XChaCha20Poly1305 {
+                nonce: to_base64(&nonce),
+            },
+            ciphertext: to_base64(&ciphertext),
+        })
+    }
+
+    pub fn decrypt(&self, passphrase: &str) -> eyre::Result<SecretKey> {
+        let Kdf::Scrypt { log_n, r, p, salt } = &self.kdf;
+        let Cipher::XChaCha20Poly1305 { nonce } = &self.cipher;
+
+        // scrypt needs 128 * r * 2^log_n bytes of memory
+        if *log_n > MAX_SCRYPT_LOG_N
+            || *r > MAX_SCRYPT_R
+            || *p > MAX_SCRYPT_P
+            || (128 * u64::from(*r)) << log_n > MAX_SCRYPT_MEMORY
+        {
+            eyre::bail!(
+                "the scrypt parameters in the keystore entry of `{}` are too expensive \
+                (log_n = {}, r = {}, p = {})",
+                self.account_id,
+                log_n,
+                r,
+                p
+            );
+        }
+
+        let cipher = XChaCha20Poly1305::new(&derive_key(
+            passphrase,
+            &from_base64(salt)?,
+            *log_n,
+            *r,
+            *p,
+        )?);
+
+        let nonce = from_base64(nonce)?;
+        if nonce.len() != 24 {
+            eyre::bail!(
+                "invalid nonce in the keystore entry of `{}`",
+                self.account_id
+            );
+        }
+
+        let plaintext = cipher
+            .decrypt(
+                nonce.as_slice().into(),
+                from_base64(&self.ciphertext)?.as_slice(),
+            )
+            .map_err(|_| eyre::eyre!("wrong passphrase for `{}`", self.account_id))?;
+
+        let secret_key: SecretKey = std::str::from_utf8(&plaintext)?.parse()?;
+
+        if secret_key.public_key() != self.public_key {
+            eyre::bail!(
+                "the keystore entry of `{}` is corrupted, its secret key doesn't match `{}`",
+                self.account_id,
+                self.public_key
+            );
+        }
+
+        Ok(secret_key)
+    }
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    log_n: u8,
+    r: u32,
+    p: u32,
+) -> eyre::Result<chacha20poly1305::Key> {
+    let params = scrypt::Params::new(log_n, r, p, 32)
+        .map_err(|err| eyre::eyre!("invalid scrypt parameters: {}", err))?;
+
+    let mut key = chacha20poly1305::Key::default();
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key)
+        .map_err(|err| eyre::eyre!("failed to derive the encryption key: {}", err))?;
+
+    Ok(key)
+}
+
+/// The keystore directory, `~/.nearx/keystore` unless `NEARX_KEYSTORE` is set.
+pub fn root() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("NEARX_KEYSTORE") {
+        return Some(PathBuf::from(path));
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+
+    Some(PathBuf::from(home).join(".nearx").join("keystore"))
+}
+
+/// Where the key of an account on a network is stored.
+pub fn path(network: &str, account_id: &AccountId) -> eyre::Result<PathBuf> {
+    check_network(network)?;
+
+    let root = root().ok_or_else(|| eyre::eyre!("couldn't locate the home directory"))?;
+
+    Ok(root.join(network).join(format!("{}.json", account_id)))
+}
+
+/// Rejects network names that would point outside of their directory in the keystore.
+fn check_network(network: &str) -> eyre::Result<()> {
+    if network.is_empty()
+        || network.contains(['/', '\\'])
+        || network.contains("..")
+        || Path::new(network).is_absolute()
+    {
+        eyre::bail!(
+            "invalid network name `{}`, it can't be empty or contain `/`, `\\` or `..`",
+            network
+        );
+    }
+
+    Ok(())
+}
+
+/// Reads the encrypted key of an account, if there is one.
+pub fn read(network: &str, account_id: &AccountId) -> eyre::Result<Option<EncryptedKey>> {
+    let path = path(network, account_id)?;
+
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|err| eyre::eyre!("failed to read `{}`: {}", path.display(), err))?;
+
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|err| eyre::eyre!("invalid keystore entry `{}`: {}", path.display(), err))
+}
+
+/// Stores an encrypted key, replacing any previous key of the account.
+///
+/// The key is written to a temporary file first and then renamed over the previous one, so that
+/// a failed write leaves it intact. Only the owner can read the key or list the directory.
+pub fn write(network: &str, key: &EncryptedKey) -> eyre::Result<PathBuf> {
+    let path = path(network, &key.account_id)?;
+    let dir = path
+        .parent()
+        .expect("keys are stored in a network directory");
+
+    let mut builder = std::fs::DirBuilder::new();
+    builder.recursive(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+
+    builder
+        .create(dir)
+        .map_err(|err| eyre::eyre!("failed to create `{}`: {}", dir.display(), err))?;
+
+    let tmp_path = dir.join(format!(
+        ".{}.json.{}.tmp",
+        key.account_id,
+        std::process::id()
+    ));
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let written = options
+        .open(&tmp_path)
+        .map_err(|err| eyre::eyre!("failed to create `{}`: {}", tmp_path.display(), err))
+        .and_then(|file| {
+            serde_json::to_writer_pretty(&file, key)?;
+            file.sync_all()?;
+            Ok(())
+        })
+        .and_then(|()| {
+            std::fs::rename(&tmp_path, &path)
+                .map_err(|err| eyre::eyre!("failed to write `{}`: {}", path.display(), err))
+        });
+
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+
+    written.map(|()| path)
+}
+
+/// Lists the encrypted keys stored for a network, or for all of them.
+pub fn list(network: Option<&str>) -> eyre::Result<Vec<(String, EncryptedKey)>> {
+    let Some(root) = root() else {
+        return Ok(vec![]);
+    };
+
+    let networks = match network {
+        Some(network) => {
+            check_network(network)?;
+            vec![network.to_owned()]
+        }
+        None if root.is_dir() => {
+            let mut networks = vec![];
+            for entry in std::fs::read_dir(&root)? {
+                let entry = entry?;
+                if entry.path().is_dir() {
+                    networks.push(entry.file_name().to_string_lossy().into_owned());
+                }
+            }
+            networks
+        }
+        None => vec![],
+    };
+
+    let mut keys = vec![];
+    for network in networks {
+        let dir = root.join(&network);
+        if !dir.is_dir() {
+            continue;
+        }
+
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension() != Some("json".as_ref()) {
+                continue;
+            }
+
+            let contents = std::fs::read_to_string(&path)?;
+            let key = serde_json::from_str::<EncryptedKey>(&contents).map_err(|err| {
+                eyre::eyre!("invalid keystore entry `{}`: {}", path.display(), err)
+            })?;
+            keys.push((network.clone(), key));
+        }
+    }
+
+    keys.sort_by(|(a_network, a), (b_network, b)| {
+        (a_network, &a.account_id).cmp(&(b_network, &b.account_id))
+    });
+
+    Ok(keys)
+}
+
+/// Reads the passphrase from `NEARX_PASSPHRASE`, or prompts for it.
+pub fn passphrase(prompt: &str) -> eyre::Result<String> {
+    if let Ok(passphrase) = env::var("NEARX_PASSPHRASE") {
+        return Ok(passphrase);
+    }
+
+    rpassword::prompt_password(prompt)
+        .map_err(|err| eyre::eyre!("failed to read the passphrase: {}", err))
+}
+
+/// Like [`passphrase`], but asks twice when prompting, to catch typos.
+pub fn new_passphrase() -> eyre::Result<String> {
+    if let Ok(passphrase) = env::var("NEARX_PASSPHRASE") {
+        return Ok(passphrase);
+    }
+
+    let passphrase = passphrase("new passphrase: ")?;
+    if passphrase != self::passphrase("repeat passphrase: ")? {
+        eyre::bail!("the passphrases don't match");
+    }
+
+    Ok(passphrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encrypted_key() -> (SecretKey, EncryptedKey) {
+        let secret_key = SecretKey::from_random(near_crypto::KeyType::ED25519);
+        let key =
+            EncryptedKey::encrypt("bob.testnet".parse().unwrap(), &secret_key, "hunter2").unwrap();
+        (secret_key, key)
+    }
+
+    #[test]
+    fn decrypts_with_the_right_passphrase_only() {
+        let (secret_key, key) = encrypted_key();
+
+        assert_eq!(key.decrypt("hunter2").unwrap(), secret_key);
+        assert!(key.decrypt("hunter3").is_err());
+    }
+
+    #[test]
+    fn rejects_a_mismatched_public_key() {
+        let (_, mut key) = encrypted_key();
+        key.public_key = SecretKey::from_random(near_crypto::KeyType::ED25519).public_key();
+
+        assert!(key.decrypt("hunter2").is_err());
+    }
+
+    #[test]
+    fn rejects_expensive_scrypt_parameters() {
+        let (_, mut key) = encrypted_key();
+
+        for (log_n, r, p) in [(21, 8, 1), (15, 33, 1), (15, 8, 17), (20, 16, 1)] {
+            let Kdf::Scrypt { salt, .. } = key.kdf;
+            key.kdf = Kdf::Scrypt { log_n, r, p, salt };
+
+            let err = key.decrypt("hunter2").unwrap_err();
+            assert!(err.to_string().contains("too expensive"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn rejects_network_names_outside_of_the_keystore() {
+        for network in ["", "..", "../..", "a/b", "a\\b", "/etc"] {
+            assert!(check_network(network).is_err(), "{}", network);
+        }
+        for network in ["testnet", "my-local.net"] {
+            assert!(check_network(network).is_ok(), "{}", network);
+        }
+    }
+}
@@ -2,17 +2,41 @@ use color_eyre::eyre;
 
 mod cli;
 mod commands;
+mod config;
+mod credentials;
+mod exit;
+mod keystore;
 pub mod macros;
 mod utils;
 
+use exit::{ExitCode, Reported, WithExitCode};
+
 async fn init() -> eyre::Result<()> {
-    let command = match cli::Command::parse()? {
-        Some(command) => command,
-        None => return Ok(()),
+    let (command, options) = match cli::Command::parse().exit_code(ExitCode::InvalidInput)? {
+        Some(parsed) => parsed,
+        None => return Err(Reported(ExitCode::InvalidInput).into()),
     };
 
     match command {
-        cli::Command::Call(command) => commands::call(command).await?,
+        cli::Command::Call(command) => commands::call(command, &options).await?,
+        cli::Command::Send(command) => commands::send(command, &options).await?,
+        cli::Command::Deploy(command) => commands::deploy(command, &options).await?,
+        cli::Command::Batch(command) => commands::batch(command, &options).await?,
+        cli::Command::Broadcast(command) => commands::broadcast(command, &options).await?,
+        cli::Command::Status(command) => commands::status(command, &options).await?,
+        cli::Command::Account(command) => commands::account(command).await?,
+        cli::Command::Keys(command) => commands::keys(command).await?,
+        cli::Command::Key(command) => commands::key(command).await?,
+        cli::Command::State(command) => commands::state(command).await?,
+        cli::Command::CreateAccount(command) => commands::create_account(command, &options).await?,
+        cli::Command::DeleteAccount(command) => commands::delete_account(command, &options).await?,
+        cli::Command::AddKey(command) => commands::add_key(command, &options).await?,
+        cli::Command::DeleteKey(command) => commands::delete_key(command, &options).await?,
+        cli::Command::Keygen(command) => commands::keygen(command).await?,
+        cli::Command::KeystoreImport(command) => commands::keystore_import(command).await?,
+        cli::Command::KeystoreList(command) => commands::keystore_list(command).await?,
+        cli::Command::KeystoreExport(command) => commands::keystore_export(command).await?,
+        cli::Command::KeystoreRemove(command) => commands::keystore_remove(command).await?,
         cli::Command::Dissect(command) => commands::dissect(command).await?,
     }
 
@@ -23,7 +47,13 @@ async fn init() -> eyre::Result<()> {
 async fn main() -> eyre::Result<()> {
     color_eyre::install()?;
 
-    init().await?;
+    if let Err(report) = init().await {
+        let code = ExitCode::of(&report);
+        if report.downcast_ref::<Reported>().is_none() {
+            eprintln!("Error: {:?}", report);
+        }
+        std::process::exit(code as i32);
+    }
 
     Ok(())
 }
@@ -1,36 +1,259 @@
-use std::borrow::Cow;
+use color_eyre::eyre;
 
 use crate::macros::warn;
 
 pub const TGAS: near_primitives::types::Gas = 1000000000000;
 pub const NEAR: near_primitives::types::Balance = 1000000000000000000000000;
 
-// todo! add parsers around this "5N", "5Tgas", "5YoctoNear", "5Near", "5Ⓝ"
-
-pub fn print_result(result: Cow<[u8]>) {
-    let mut result = result;
-    if let Ok(utf8_result) = std::str::from_utf8(&result) {
-        if let Ok(json) = utf8_result.parse::<serde_json::Value>() {
-            match serde_json::from_value::<Vec<u8>>(json.clone()) {
-                Ok(bytes) if !bytes.is_empty() => {
-                    result = Cow::from(bytes);
-                }
-                _ => {
-                    println!(
-                        "{}",
-                        serde_json::to_string_pretty(&json).expect("json is valid")
-                    );
-                    return;
-                }
-            }
-        } else {
-            println!("{}", utf8_result);
-            return;
-        }
-    }
-    warn!("the result is not valid utf-8");
-    for byte in result.as_ref() {
-        print!("{:02x}", byte);
-    }
-    println!();
+const GAS_UNITS: &[(&str, u128)] = &[("tgas", TGAS as u128), ("ggas", 1000000000), ("gas", 1)];
+
+const NEAR_UNITS: &[(&str, u128)] = &[
+    ("near", NEAR),
+    ("n", NEAR),
+    ("Ⓝ", NEAR),
+    ("millinear", NEAR / 1000),
+    ("yoctonear", 1),
+    ("yocto", 1),
+];
+
+/// Parses an amount of gas, e.g. `"300Tgas"`, `"2.5 Ggas"`, `"1_000_000"`.
+///
+/// Bare numbers are interpreted as raw gas units.
+pub fn parse_gas(s: &str) -> eyre::Result<near_primitives::types::Gas> {
+    let gas = parse_amount(s, GAS_UNITS, Some(1))?;
+    near_primitives::types::Gas::try_from(gas)
+        .map_err(|_| eyre::eyre!("gas amount `{}` is too large", s))
+}
+
+/// Parses an amount of NEAR, e.g. `"5N"`, `"5Ⓝ"`, `"0.25 NEAR"`, `"10yocto"`.
+///
+/// The unit is required, so `"5"` can't be mistaken for 5 NEAR when it means 5 yoctoNEAR.
+pub fn parse_balance(s: &str) -> eyre::Result<near_primitives::types::Balance> {
+    parse_amount(s, NEAR_UNITS, None)
+}
+
+/// Bare numbers are scaled by `bare_multiplier`, or rejected without one.
+fn parse_amount(
+    s: &str,
+    units: &[(&str, u128)],
+    bare_multiplier: Option<u128>,
+) -> eyre::Result<u128> {
+    let s = s.trim();
+
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_'))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let unit = unit.trim_start();
+
+    let expected = || {
+        units
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let multiplier = match (unit, bare_multiplier) {
+        ("", Some(multiplier)) => multiplier,
+        ("", None) => eyre::bail!("missing unit in `{}`, expected one of: {}", s, expected()),
+        (unit, _) => match units
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+        {
+            Some((_, multiplier)) => *multiplier,
+            None => eyre::bail!("unknown unit `{}`, expected one of: {}", unit, expected()),
+        },
+    };
+
+    let number = number.replace('_', "");
+    let (whole, fraction) = number.split_once('.').unwrap_or((&number, ""));
+
+    if whole.is_empty() && fraction.is_empty() {
+        eyre::bail!("missing numeric value in `{}`", s);
+    }
+
+    if fraction.contains('.') {
+        eyre::bail!("invalid number `{}`", number);
+    }
+
+    let overflow = || eyre::eyre!("amount `{}` is too large", s);
+
+    let whole = match whole {
+        "" => 0,
+        whole => whole.parse::<u128>().map_err(|_| overflow())?,
+    };
+
+    let mut amount = whole.checked_mul(multiplier).ok_or_else(overflow)?;
+
+    let fraction = fraction.trim_end_matches('0');
+    if !fraction.is_empty() {
+        let scale = u32::try_from(fraction.len())
+            .ok()
+            .and_then(|len| 10u128.checked_pow(len))
+            .filter(|scale| multiplier % scale == 0)
+            .ok_or_else(|| eyre::eyre!("amount `{}` is more precise than the smallest unit", s))?;
+
+        let fraction = fraction.parse::<u128>().map_err(|_| overflow())?;
+
+        amount = amount
+            .checked_add(fraction * (multiplier / scale))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(amount)
+}
+
+/// Formats an amount of yoctoNEAR as NEAR, without losing precision, e.g. `"1.5 NEAR"`.
+pub fn format_balance(balance: near_primitives::types::Balance) -> String {
+    let fraction = format!("{:024}", balance % NEAR);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        format!("{} NEAR", balance / NEAR)
+    } else {
+        format!("{}.{} NEAR", balance / NEAR, fraction)
+    }
+}
+
+/// A return value, decoded as JSON when possible.
+#[derive(Debug, PartialEq)]
+pub enum CallResult {
+    Json(serde_json::Value),
+    Text(String),
+    /// Raw bytes, or a JSON array of them, like contracts returning a `Vec<u8>` produce.
+    Bytes(Vec<u8>),
+}
+
+/// Decodes a return value as JSON, falling back to a string, then bytes.
+pub fn decode_result(result: &[u8]) -> CallResult {
+    let Ok(utf8_result) = std::str::from_utf8(result) else {
+        warn!("the result is not valid utf-8");
+        return CallResult::Bytes(result.to_vec());
+    };
+
+    let Ok(json) = utf8_result.parse::<serde_json::Value>() else {
+        return CallResult::Text(utf8_result.to_owned());
+    };
+
+    match serde_json::from_value::<Vec<u8>>(json.clone()) {
+        Ok(bytes) if !bytes.is_empty() => CallResult::Bytes(bytes),
+        _ => CallResult::Json(json),
+    }
+}
+
+/// Decodes a return value for structured output, with bytes in hex, like [`print_result`].
+pub fn result_to_json(result: &[u8]) -> serde_json::Value {
+    match decode_result(result) {
+        CallResult::Json(json) => json,
+        CallResult::Text(text) => serde_json::Value::String(text),
+        CallResult::Bytes(bytes) => serde_json::Value::String(hex(&bytes)),
+    }
+}
+
+pub fn print_result(result: &[u8]) {
+    match decode_result(result) {
+        CallResult::Json(json) => println!(
+            "{}",
+            serde_json::to_string_pretty(&json).expect("json is valid")
+        ),
+        CallResult::Text(text) => println!("{}", text),
+        CallResult::Bytes(bytes) => println!("{}", hex(&bytes)),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_balances_in_every_unit() {
+        assert_eq!(parse_balance("5N").unwrap(), 5 * NEAR);
+        assert_eq!(parse_balance("5 NEAR").unwrap(), 5 * NEAR);
+        assert_eq!(parse_balance("5Ⓝ").unwrap(), 5 * NEAR);
+        assert_eq!(parse_balance("5 milliNEAR").unwrap(), 5 * NEAR / 1000);
+        assert_eq!(parse_balance("5yocto").unwrap(), 5);
+        assert_eq!(parse_balance("5 yoctoNEAR").unwrap(), 5);
+    }
+
+    #[test]
+    fn scales_decimals() {
+        assert_eq!(parse_balance("0.25N").unwrap(), NEAR / 4);
+        assert_eq!(parse_balance(".5N").unwrap(), NEAR / 2);
+        assert_eq!(parse_balance("1.50N").unwrap(), 3 * NEAR / 2);
+        assert_eq!(parse_balance("0.001 millinear").unwrap(), NEAR / 1_000_000);
+        assert_eq!(parse_gas("2.5Tgas").unwrap(), 5 * TGAS / 2);
+        assert_eq!(parse_gas("1.5 Ggas").unwrap(), 1_500_000_000);
+    }
+
+    #[test]
+    fn ignores_separators() {
+        assert_eq!(parse_balance("1_000N").unwrap(), 1000 * NEAR);
+        assert_eq!(parse_gas("300_000_000_000_000").unwrap(), 300 * TGAS);
+    }
+
+    #[test]
+    fn rejects_more_precision_than_the_smallest_unit() {
+        assert!(parse_balance("1.5yocto").is_err());
+        assert!(parse_balance("0.0000000000000000000000001N").is_err());
+        assert!(parse_gas("1.5").is_err());
+        assert!(parse_gas("0.0000000000001Tgas").is_err());
+    }
+
+    #[test]
+    fn rejects_overflows() {
+        assert_eq!(
+            parse_balance(&format!("{}yocto", u128::MAX)).unwrap(),
+            u128::MAX
+        );
+        assert!(parse_balance(&format!("{}0yocto", u128::MAX)).is_err());
+        assert!(parse_balance("1000000000000000N").is_err());
+        assert!(parse_gas(&format!("{}", u64::MAX as u128 + 1)).is_err());
+        assert!(parse_gas("100000000Tgas").is_err());
+    }
+
+    #[test]
+    fn requires_a_unit_for_balances_but_not_for_gas() {
+        assert!(parse_balance("5").is_err());
+        assert_eq!(parse_gas("5").unwrap(), 5);
+    }
+
+    #[test]
+    fn rejects_malformed_amounts() {
+        assert!(parse_balance("N").is_err());
+        assert!(parse_balance("1.2.3N").is_err());
+        assert!(parse_balance("5 lightyears").is_err());
+        assert!(parse_balance("-5N").is_err());
+    }
+
+    #[test]
+    fn decodes_results_the_same_for_json_and_plain_output() {
+        assert_eq!(
+            decode_result(br#"{"a":1}"#),
+            CallResult::Json(serde_json::json!({ "a": 1 }))
+        );
+        assert_eq!(
+            decode_result(b"[]"),
+            CallResult::Json(serde_json::json!([]))
+        );
+        assert_eq!(
+            decode_result(b"hello"),
+            CallResult::Text("hello".to_owned())
+        );
+        assert_eq!(
+            decode_result(b"[1,2,255]"),
+            CallResult::Bytes(vec![1, 2, 255])
+        );
+        assert_eq!(
+            decode_result(&[0xff, 0x00]),
+            CallResult::Bytes(vec![0xff, 0x00])
+        );
+
+        assert_eq!(result_to_json(b"[1,2,255]"), "0102ff");
+        assert_eq!(result_to_json(b"[1,2,256]"), serde_json::json!([1, 2, 256]));
+    }
 }